@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+"""Generates the MAC Address Tool's OUI table from the IEEE MA-L registry.
+
+Download the registry from https://standards-oui.ieee.org/oui/oui.csv and run:
+
+    python3 scripts/generate_oui_registry.py oui.csv
+
+The table is a big-endian u32 entry count, then the entries sorted by OUI, each a 3-byte
+OUI and a 3-byte big-endian offset into the newline-terminated organization names that
+follow. Organizations with several OUIs share one name. The table is zlib-compressed to
+keep it small in the web build.
+"""
+
+import csv
+import sys
+import zlib
+from pathlib import Path
+
+OUTPUT = Path(__file__).parent.parent / "src/pages/encoder_decoder/oui_registry.bin.zlib"
+
+
+def main(registry_path):
+    vendors = {}
+    with open(registry_path, newline="", encoding="utf-8") as registry:
+        for row in csv.DictReader(registry):
+            if row["Registry"] != "MA-L":
+                continue
+            name = " ".join(row["Organization Name"].split())
+            vendors.setdefault(int(row["Assignment"], 16), name)
+
+    names = bytearray()
+    name_offsets = {}
+    entries = bytearray(len(vendors).to_bytes(4, "big"))
+    for oui, name in sorted(vendors.items()):
+        if name not in name_offsets:
+            name_offsets[name] = len(names)
+            names += name.encode("utf-8") + b"\n"
+        entries += oui.to_bytes(3, "big") + name_offsets[name].to_bytes(3, "big")
+
+    OUTPUT.write_bytes(zlib.compress(entries + names, 9))
+    print(f"Wrote {len(vendors)} OUIs and {len(name_offsets)} organizations to {OUTPUT}")
+
+
+if __name__ == "__main__":
+    main(sys.argv[1])
@@ -0,0 +1,51 @@
+#![allow(non_snake_case)]
+use base64ct::{Base64, Encoding};
+use dioxus::prelude::*;
+
+use crate::components::inputs::TextInput;
+use crate::utils::{add_number_delimiters, detect_file_signature};
+
+/// Shows the detected file type and size of decoded bytes, with an image preview and a
+/// download link.
+#[component]
+pub fn DecodedBytesView(bytes: Vec<u8>) -> Element {
+    if bytes.is_empty() {
+        return rsx! {};
+    }
+
+    let signature = detect_file_signature(&bytes);
+    let (file_type, mime_type, extension) = match signature {
+        Some(signature) => (signature.name, signature.mime_type, signature.extension),
+        None if std::str::from_utf8(&bytes).is_err() => {
+            ("Unknown binary data", "application/octet-stream", "bin")
+        }
+        None => ("UTF-8 text", "text/plain", "txt"),
+    };
+    let data_uri = format!("data:{mime_type};base64,{}", Base64::encode_string(&bytes));
+
+    rsx! {
+        div { class: "widget-params",
+            TextInput {
+                label: "Detected File Type",
+                value: file_type,
+                readonly: true,
+            }
+            TextInput {
+                label: "Decoded Size",
+                value: "{add_number_delimiters(bytes.len().to_string(), ',', 3)} bytes",
+                readonly: true,
+            }
+        }
+        if mime_type.starts_with("image/") {
+            img { class: "decoded-image", src: "{data_uri}" }
+        }
+        div { class: "widget-buttons",
+            a {
+                class: "btn btn-info",
+                download: "decoded.{extension}",
+                href: "{data_uri}",
+                "Save Decoded Bytes"
+            }
+        }
+    }
+}
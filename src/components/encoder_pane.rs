@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use std::fmt;
+
+use crate::components::inputs::TextAreaForm;
+use crate::utils::hex_dump;
+
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum Direction {
+    #[default]
+    Encode,
+    Decode,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The contents of the decoded and encoded panes of an encoder widget.
+#[derive(Clone, Default)]
+pub struct EncoderText {
+    pub encoded_value: String,
+    pub decoded_value: String,
+    pub decoded_bytes: Vec<u8>,
+    /// The pane the user last typed into, which is kept when an option changes.
+    pub last_edited: Direction,
+}
+
+impl EncoderText {
+    /// Stores decoded bytes, showing them as text when they are valid UTF-8 and as a hex dump
+    /// otherwise.
+    pub fn set_decoded_bytes(&mut self, bytes: Vec<u8>) {
+        self.decoded_value = match std::str::from_utf8(&bytes) {
+            Ok(text) => text.to_string(),
+            Err(_) => hex_dump(&bytes),
+        };
+        self.decoded_bytes = bytes;
+    }
+
+    pub fn is_binary(&self) -> bool {
+        std::str::from_utf8(&self.decoded_bytes).is_err()
+    }
+}
+
+/// One pane of an encoder widget. The decoded pane shows text, or a read-only hex dump when
+/// the bytes are not UTF-8, since typing into the dump would replace the bytes with its text.
+#[component]
+pub fn EncoderPane(
+    direction: Direction,
+    value: String,
+    is_binary: bool,
+    encoded_label: String,
+    binary_label: Option<String>,
+    encoded_class: Option<String>,
+    oninput: EventHandler<String>,
+) -> Element {
+    rsx! {
+        TextAreaForm {
+            class: match direction {
+                Direction::Encode if is_binary => Some("hex-dump".to_string()),
+                Direction::Encode => None,
+                Direction::Decode => encoded_class,
+            },
+            label: match direction {
+                Direction::Encode if is_binary => {
+                    binary_label.unwrap_or("Decoded Bytes (hex dump)".to_string())
+                }
+                Direction::Encode => "Text".to_string(),
+                Direction::Decode => encoded_label,
+            },
+            value: "{value}",
+            readonly: direction == Direction::Encode && is_binary,
+            oninput: move |event: Event<FormData>| oninput.call(event.value()),
+        }
+    }
+}
@@ -2,9 +2,12 @@
 use std::fmt::{Debug, Display};
 use std::str::FromStr;
 
-use dioxus::prelude::*;
+use dioxus::{
+    html::{FileData, HasFileData},
+    prelude::*,
+};
 use dioxus_free_icons::{
-    icons::fa_solid_icons::{FaChevronDown, FaChevronUp},
+    icons::fa_solid_icons::{FaChevronDown, FaChevronUp, FaCopy},
     Icon,
 };
 use num_traits::PrimInt;
@@ -129,6 +132,81 @@ pub fn TextInput(
     }
 }
 
+/// Copies `value` to the clipboard through the webview's clipboard API.
+#[component]
+pub fn CopyButton(value: String) -> Element {
+    rsx! {
+        button {
+            class: "btn btn-square copy-button",
+            r#type: "button",
+            title: "Copy",
+            onclick: move |_| {
+                let text = serde_json::to_string(&value).unwrap_or_default();
+                document::eval(&format!("navigator.clipboard.writeText({text});"));
+            },
+            Icon { icon: FaCopy, width: 14, height: 14 }
+        }
+    }
+}
+
+/// A file read into memory by a `FileInput`.
+#[derive(Clone, PartialEq)]
+pub struct LoadedFile {
+    pub name: String,
+    pub bytes: Vec<u8>,
+}
+
+#[component]
+pub fn FileInput(
+    label: String,
+    accept: Option<String>,
+    onload: EventHandler<LoadedFile>,
+) -> Element {
+    let mut is_dragging = use_signal(|| false);
+
+    let load_files = move |files: Vec<FileData>| {
+        if let Some(file) = files.into_iter().next() {
+            spawn(async move {
+                match file.read_bytes().await {
+                    Ok(bytes) => onload.call(LoadedFile {
+                        name: file.name(),
+                        bytes: bytes.to_vec(),
+                    }),
+                    Err(err) => tracing::error!("Failed to read file {}: {:?}", file.name(), err),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: if *is_dragging.read() { "file-input-form dragging" } else { "file-input-form" },
+            ondragover: move |event| {
+                event.prevent_default();
+                is_dragging.set(true);
+            },
+            ondragleave: move |_| {
+                is_dragging.set(false);
+            },
+            ondrop: move |event| {
+                event.prevent_default();
+                is_dragging.set(false);
+                load_files(event.files());
+            },
+            input {
+                r#type: "file",
+                id: "{label}",
+                accept: accept.unwrap_or_default(),
+                onchange: move |event| {
+                    load_files(event.files());
+                },
+            }
+            label { r#for: "{label}", "{label}" }
+            span { class: "file-input-hint", "or drop a file here" }
+        }
+    }
+}
+
 #[component]
 pub fn NumberInput<T: PrimInt + Display + Default + FromStr + 'static>(
     class: Option<&'static str>,
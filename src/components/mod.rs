@@ -1,2 +1,4 @@
 pub mod accordion;
+pub mod decoded_bytes;
+pub mod encoder_pane;
 pub mod inputs;
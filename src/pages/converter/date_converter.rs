@@ -4,11 +4,21 @@ use std::{fmt::Display, str::FromStr};
 use dioxus::prelude::*;
 use dioxus_free_icons::icons::fa_solid_icons::FaClock;
 use strum::IntoEnumIterator;
-use time::{Month, OffsetDateTime, UtcOffset};
-use time_tz::{system, timezones, OffsetDateTimeExt, TimeZone, Tz};
+use strum_macros::{Display as StrumDisplay, EnumIter, EnumString, IntoStaticStr};
+use time::{
+    format_description::{
+        well_known::{Iso8601, Rfc2822, Rfc3339},
+        BorrowedFormatItem,
+    },
+    macros::{datetime, format_description},
+    Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
+use time_tz::{
+    system, timezones, OffsetDateTimeExt, OffsetResult, PrimitiveDateTimeExt, TimeZone, Tz,
+};
 
 use crate::{
-    components::inputs::{NumberInput, SelectForm, SelectFormEnum, TextInput},
+    components::inputs::{CopyButton, NumberInput, SelectForm, SelectFormEnum, TextInput},
     pages::{WidgetEntry, WidgetIcon},
 };
 
@@ -25,38 +35,92 @@ pub fn DateConverter() -> Element {
     let mut date_signal = use_signal(|| DateConverterState {
         time_zone: DcTimeZone::default(),
         time_utc: OffsetDateTime::now_utc(),
+        date_order: DateOrder::default(),
+        date_input: None,
+        parsed_date: None,
+        timestamp_unit: TimestampUnit::default(),
+        detected_unit: None,
+        timestamp_input: None,
+        parsed_timestamp: None,
     });
+    let mut custom_format = use_signal(|| "%A, %d %B %Y %H:%M:%S %z".to_string());
 
     let local_datetime = date_signal.with(|date_state| date_state.local_datetime());
-    let unix_time = date_signal.with(|date_state| date_state.time_utc.unix_timestamp());
+    let date_text = date_signal.with(|date_state| {
+        date_state
+            .date_input
+            .clone()
+            .unwrap_or_else(|| date_input_text(local_datetime))
+    });
+    let parsed_date = date_signal.with(|date_state| date_state.parsed_date.clone());
+    let custom_output = format_custom(local_datetime, &custom_format.read());
 
     rsx! {
         div { class: "widget-grid date-converter",
-            SelectForm::<DcTimeZone> {
-                label: "Time Zone",
-                oninput: move |tz: DcTimeZone| {
+            div { class: "widget-params",
+                SelectForm::<DcTimeZone> {
+                    label: "Time Zone",
+                    oninput: move |tz: DcTimeZone| {
+                        date_signal
+                            .with_mut(|date_state| {
+                                date_state.time_zone = tz;
+                            });
+                    },
+                    value: date_signal.with(|date_state| date_state.time_zone),
+                }
+                SelectForm::<DateOrder> {
+                    label: "Ambiguous Numeric Dates",
+                    oninput: move |date_order: DateOrder| {
+                        date_signal
+                            .with_mut(|date_state| {
+                                date_state.date_order = date_order;
+                            });
+                    },
+                    value: date_signal.with(|date_state| date_state.date_order),
+                }
+                SelectForm::<TimestampUnit> {
+                    label: "Unix Timestamp Unit",
+                    oninput: move |unit: TimestampUnit| {
+                        date_signal
+                            .with_mut(|date_state| {
+                                date_state.timestamp_unit = unit;
+                                date_state.detected_unit = None;
+                            });
+                    },
+                    value: date_signal.with(|date_state| date_state.timestamp_unit),
+                }
+            }
+            TextInput {
+                label: "Date (type or paste any format)",
+                value: "{date_text}",
+                oninput: move |event: Event<FormData>| {
+                    date_signal
+                        .with_mut(|date_state| {
+                            date_state.parse_date_input(event.value());
+                        });
+                },
+                onchange: move |_| {
                     date_signal
                         .with_mut(|date_state| {
-                            date_state.time_zone = tz;
+                            date_state.date_input = None;
                         });
                 },
-                value: date_signal.with(|date_state| date_state.time_zone),
             }
-            TextInput { label: "Date", value: "{local_datetime}", readonly: true }
-            TextInput {
-                label: "Unix Timestamp",
-                value: "{unix_time}",
-                onchange: move |event: Event<FormData>| {
-                    if let Ok(unix_time) = event.parsed::<i64>() {
-                        if let Ok(datetime) = OffsetDateTime::from_unix_timestamp(unix_time) {
-                            date_signal
-                                .with_mut(|date_state| {
-                                    date_state.set_local_datetime(datetime);
-                                });
+            match parsed_date {
+                Some(Ok(parsed_date)) => rsx! {
+                    div { class: "alert alert-info m-0 flex-col items-start gap-1",
+                        span { "Read as {parsed_date.format}." }
+                        for note in parsed_date.notes {
+                            span { "{note}" }
                         }
                     }
                 },
+                Some(Err(error)) => rsx! {
+                    div { class: "alert alert-warning m-0", "{error}" }
+                },
+                None => rsx! {},
             }
+            TimestampField { kind: TimestampKind::Unix, date_signal }
             div { class: "selectors-wrapper",
                 div { class: "ymd selectors",
                     div { class: "selectors-inner",
@@ -155,13 +219,229 @@ pub fn DateConverter() -> Element {
                     }
                 }
             }
+            for (label , value) in date_outputs(local_datetime) {
+                DateOutput { key: "{label}", label, value }
+            }
+            for kind in TimestampKind::iter().filter(|kind| *kind != TimestampKind::Unix) {
+                TimestampField { key: "{kind:?}", kind, date_signal }
+            }
+            TextInput {
+                label: "Custom Format (strftime such as %Y-%m-%d, or time such as [year]-[month]-[day])",
+                value: "{custom_format}",
+                oninput: move |event: Event<FormData>| custom_format.set(event.value()),
+            }
+            match custom_output {
+                Ok(value) => rsx! {
+                    DateOutput { label: "Custom Format Preview", value }
+                },
+                Err(error) => rsx! {
+                    div { class: "alert alert-warning m-0", "{error}" }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn DateOutput(label: &'static str, value: String) -> Element {
+    rsx! {
+        div { class: "date-output",
+            TextInput { label, value: value.clone(), readonly: true }
+            CopyButton { value }
         }
     }
 }
 
+/// An editable timestamp counted from one of the supported epochs.
+#[component]
+fn TimestampField(kind: TimestampKind, date_signal: Signal<DateConverterState>) -> Element {
+    let (label, value, parsed_timestamp) = date_signal.with(|date_state| {
+        (
+            kind.label(date_state.unix_unit()),
+            date_state.timestamp_text(kind),
+            date_state.parsed_timestamp(kind),
+        )
+    });
+
+    rsx! {
+        div { class: "date-output",
+            TextInput {
+                label,
+                value: value.clone(),
+                oninput: move |event: Event<FormData>| {
+                    date_signal
+                        .with_mut(|date_state| {
+                            date_state.parse_timestamp_input(kind, event.value());
+                        });
+                },
+                onchange: move |_| {
+                    date_signal
+                        .with_mut(|date_state| {
+                            date_state.timestamp_input = None;
+                        });
+                },
+            }
+            CopyButton { value }
+        }
+        match parsed_timestamp {
+            Some(Ok(notes)) => rsx! {
+                div { class: "alert alert-info m-0 flex-col items-start gap-1",
+                    for note in notes {
+                        span { "{note}" }
+                    }
+                }
+            },
+            Some(Err(error)) => rsx! {
+                div { class: "alert alert-warning m-0", "{error}" }
+            },
+            None => rsx! {},
+        }
+    }
+}
+
+/// The date in each standard output format, or why a format cannot represent it.
+fn date_outputs(datetime: OffsetDateTime) -> Vec<(&'static str, String)> {
+    let fraction = fraction_suffix(datetime);
+    let is_utc = datetime.offset().is_utc();
+    let format = |description: &[BorrowedFormatItem]| {
+        datetime
+            .format(description)
+            .unwrap_or_else(|err| format!("Not representable: {err}"))
+    };
+    let extended_offset = match is_utc {
+        true => "Z".to_string(),
+        false => format(format_description!(
+            "[offset_hour sign:mandatory]:[offset_minute]"
+        )),
+    };
+    let basic_offset = match is_utc {
+        true => "Z".to_string(),
+        false => format(format_description!(
+            "[offset_hour sign:mandatory][offset_minute]"
+        )),
+    };
+    let days_in_year = time::util::days_in_year(datetime.year());
+    vec![
+        (
+            "RFC 3339",
+            datetime
+                .format(&Rfc3339)
+                .unwrap_or_else(|err| format!("Not representable: {err}")),
+        ),
+        (
+            "ISO 8601 Extended",
+            format!(
+                "{}{fraction}{extended_offset}",
+                format(format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"))
+            ),
+        ),
+        (
+            "ISO 8601 Basic",
+            format!(
+                "{}{fraction}{basic_offset}",
+                format(format_description!("[year][month][day]T[hour][minute][second]"))
+            ),
+        ),
+        (
+            "RFC 2822",
+            datetime
+                .format(&Rfc2822)
+                .unwrap_or_else(|err| format!("Not representable: {err}")),
+        ),
+        (
+            "HTTP-date",
+            datetime
+                .to_offset(UtcOffset::UTC)
+                .format(format_description!(
+                    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+                ))
+                .unwrap_or_else(|err| format!("Not representable: {err}")),
+        ),
+        (
+            "SQL Timestamp",
+            format!(
+                "{}{fraction}",
+                format(format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"))
+            ),
+        ),
+        (
+            "ISO Week Date",
+            format(format_description!(
+                "[year base:iso_week]-W[week_number repr:iso]-[weekday repr:monday]"
+            )),
+        ),
+        (
+            "Day of Year",
+            format!(
+                "{} (day {} of {days_in_year})",
+                format(format_description!("[year]-[ordinal]")),
+                datetime.ordinal()
+            ),
+        ),
+    ]
+}
+
+/// The sub-second part as `.123` without trailing zeros, or nothing for whole seconds.
+/// The date as the Date field shows it, in the SQL and log style that the field reads back.
+fn date_input_text(datetime: OffsetDateTime) -> String {
+    let offset = match datetime.offset().seconds_past_minute() {
+        0 => datetime.format(format_description!(
+            "[offset_hour sign:mandatory]:[offset_minute]"
+        )),
+        // Local mean time offsets from before standard time zones have seconds.
+        _ => datetime.format(format_description!(
+            "[offset_hour sign:mandatory]:[offset_minute]:[offset_second]"
+        )),
+    };
+    format!(
+        "{}{} {}",
+        datetime
+            .format(format_description!(
+                "[year]-[month]-[day] [hour]:[minute]:[second]"
+            ))
+            .unwrap_or_default(),
+        fraction_suffix(datetime),
+        offset.unwrap_or_default()
+    )
+}
+
+fn fraction_suffix(datetime: OffsetDateTime) -> String {
+    match datetime.nanosecond() {
+        0 => String::new(),
+        nanoseconds => format!(".{nanoseconds:09}")
+            .trim_end_matches('0')
+            .to_string(),
+    }
+}
+
+/// Formats with a strftime string when it contains `%`, and with a `time` format
+/// description such as `[year]-[month]-[day]` otherwise.
+fn format_custom(datetime: OffsetDateTime, format: &str) -> Result<String, String> {
+    let description = match format.contains('%') {
+        true => time::format_description::parse_strftime_owned(format),
+        false => time::format_description::parse_owned::<2>(format),
+    }
+    .map_err(|err| format!("Invalid format: {err}"))?;
+    datetime
+        .format(&description)
+        .map_err(|err| format!("Cannot format the date: {err}"))
+}
+
 struct DateConverterState {
     time_zone: DcTimeZone,
     time_utc: OffsetDateTime,
+    date_order: DateOrder,
+    /// The raw text of the date field while it is being edited.
+    date_input: Option<String>,
+    /// How the last date typed into the date field was read.
+    parsed_date: Option<Result<ParsedDate, String>>,
+    timestamp_unit: TimestampUnit,
+    /// The unit last detected in a Unix timestamp while the unit is set to auto-detect.
+    detected_unit: Option<TimestampUnit>,
+    /// The raw text of a timestamp field while it is being edited.
+    timestamp_input: Option<(TimestampKind, String)>,
+    /// How the last timestamp typed into a timestamp field was read.
+    parsed_timestamp: Option<(TimestampKind, Result<Vec<String>, String>)>,
 }
 
 impl DateConverterState {
@@ -171,11 +451,835 @@ impl DateConverterState {
 
     fn set_local_datetime(&mut self, datetime: OffsetDateTime) {
         self.time_utc = datetime.to_offset(UtcOffset::UTC);
+        self.date_input = None;
+        self.parsed_date = None;
+        self.timestamp_input = None;
+        self.parsed_timestamp = None;
+    }
+
+    fn parse_date_input(&mut self, input: String) {
+        let parsed_date = parse_date_string(
+            &input,
+            self.time_zone.inner(),
+            self.date_order,
+            OffsetDateTime::now_utc(),
+        );
+        if let Ok(parsed_date) = &parsed_date {
+            self.time_utc = parsed_date.datetime.to_offset(UtcOffset::UTC);
+        }
+        self.parsed_date = Some(parsed_date);
+        self.date_input = Some(input);
+        self.timestamp_input = None;
+        self.parsed_timestamp = None;
+    }
+
+    /// The unit Unix timestamps are shown in.
+    fn unix_unit(&self) -> TimestampUnit {
+        match self.timestamp_unit {
+            TimestampUnit::Auto => self.detected_unit.unwrap_or(TimestampUnit::Seconds),
+            unit => unit,
+        }
+    }
+
+    fn timestamp_text(&self, kind: TimestampKind) -> String {
+        match &self.timestamp_input {
+            Some((input_kind, input)) if *input_kind == kind => input.clone(),
+            _ => kind.format(self.local_datetime(), self.unix_unit()),
+        }
+    }
+
+    fn parsed_timestamp(&self, kind: TimestampKind) -> Option<Result<Vec<String>, String>> {
+        match &self.parsed_timestamp {
+            Some((parsed_kind, parsed)) if *parsed_kind == kind => Some(parsed.clone()),
+            _ => None,
+        }
+    }
+
+    fn parse_timestamp_input(&mut self, kind: TimestampKind, input: String) {
+        let detect = kind == TimestampKind::Unix && self.timestamp_unit == TimestampUnit::Auto;
+        let unit = match detect {
+            true => TimestampUnit::detect(&input),
+            false => self.timestamp_unit,
+        };
+        let parsed = kind
+            .parse(&input, unit, self.time_zone.inner())
+            .map(|(datetime, mut notes)| {
+                self.time_utc = datetime.to_offset(UtcOffset::UTC);
+                if detect {
+                    self.detected_unit = Some(unit);
+                    notes.push(
+                        "The unit was detected from the number of digits; choose one in Unix Timestamp Unit to override it."
+                            .to_string(),
+                    );
+                }
+                notes
+            });
+        self.parsed_timestamp = Some((kind, parsed));
+        self.timestamp_input = Some((kind, input));
+        self.date_input = None;
+        self.parsed_date = None;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct ParsedDate {
+    datetime: OffsetDateTime,
+    format: &'static str,
+    /// How missing or ambiguous parts of the input were filled in.
+    notes: Vec<String>,
+}
+
+/// A date string read by one of the supported formats, before a time zone is applied.
+enum DateValue {
+    Offset(OffsetDateTime),
+    Local(PrimitiveDateTime),
+    Date(Date),
+}
+
+/// Formats without an offset, tried after a trailing offset or zone name has been split off.
+const LOCAL_FORMATS: &[(&str, &[BorrowedFormatItem])] = &[
+    (
+        "date and time (SQL and log style)",
+        format_description!(
+            version = 2,
+            "[year]-[month]-[day] [hour]:[minute][optional [:[second][optional [[first [.] [,]][subsecond]]]]]"
+        ),
+    ),
+    (
+        "year/month/day date and time",
+        format_description!(
+            version = 2,
+            "[year]/[month]/[day] [hour]:[minute][optional [:[second][optional [.[subsecond]]]]]"
+        ),
+    ),
+    (
+        "Common Log Format (Apache, Nginx)",
+        format_description!(
+            version = 2,
+            "[day]/[month repr:short case_sensitive:false]/[year]:[hour]:[minute]:[second]"
+        ),
+    ),
+    (
+        "ANSI C asctime",
+        format_description!(
+            version = 2,
+            "[weekday repr:short case_sensitive:false] [month repr:short case_sensitive:false] [day padding:none] [hour]:[minute]:[second] [year]"
+        ),
+    ),
+];
+
+/// Dates with a month name and no time of day.
+const DATE_FORMATS: &[(&str, &[BorrowedFormatItem])] = &[
+    (
+        "month name, day, year",
+        format_description!(
+            version = 2,
+            "[month repr:long case_sensitive:false] [day padding:none], [year]"
+        ),
+    ),
+    (
+        "month name, day, year",
+        format_description!(
+            version = 2,
+            "[month repr:short case_sensitive:false] [day padding:none], [year]"
+        ),
+    ),
+    (
+        "day, month name, year",
+        format_description!(
+            version = 2,
+            "[day padding:none] [month repr:long case_sensitive:false] [year]"
+        ),
+    ),
+    (
+        "day, month name, year",
+        format_description!(
+            version = 2,
+            "[day padding:none] [month repr:short case_sensitive:false] [year]"
+        ),
+    ),
+];
+
+const RFC_850_FORMAT: &[BorrowedFormatItem] = format_description!(
+    version = 2,
+    "[weekday case_sensitive:false], [day]-[month repr:short case_sensitive:false]-[year] [hour]:[minute]:[second]"
+);
+
+/// Syslog (RFC 3164) timestamps have no year, so the current one is prepended before parsing.
+const SYSLOG_FORMAT: &[BorrowedFormatItem] = format_description!(
+    version = 2,
+    "[year] [month repr:short case_sensitive:false] [day padding:none] [hour]:[minute]:[second][optional [.[subsecond]]]"
+);
+
+const TIME_OF_DAY_FORMAT: &[BorrowedFormatItem] = format_description!(
+    version = 2,
+    "[hour]:[minute][optional [:[second][optional [[first [.] [,]][subsecond]]]]]"
+);
+
+/// Reads a date string in any of the supported formats. Inputs without an offset are read as
+/// local time in `time_zone`, and `date_order` decides numeric dates such as `03/04/2024`
+/// where both numbers could be the month.
+fn parse_date_string(
+    input: &str,
+    time_zone: &'static Tz,
+    date_order: DateOrder,
+    now: OffsetDateTime,
+) -> Result<ParsedDate, String> {
+    let trimmed = input.trim().trim_matches(['[', ']', '"', '\'']).trim();
+    if trimmed.is_empty() {
+        return Err("Enter a date to parse.".to_string());
+    }
+    // Log formats pad days with spaces, as in `Nov  6`.
+    let normalized = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
+    let current_year = now.to_timezone(time_zone).year();
+    let (value, format, mut notes) = match_date_format(&normalized, date_order, current_year)
+        .ok_or_else(|| format!("\"{trimmed}\" does not match any supported date format."))?;
+
+    let datetime = match value {
+        DateValue::Offset(datetime) => datetime,
+        DateValue::Local(datetime) => assume_time_zone(datetime, time_zone, &mut notes),
+        DateValue::Date(date) => {
+            notes.push("No time of day given, so midnight was used.".to_string());
+            assume_time_zone(date.midnight(), time_zone, &mut notes)
+        }
+    };
+    notes.extend(weekday_mismatch(&normalized, datetime.date()));
+    Ok(ParsedDate {
+        datetime,
+        format,
+        notes,
+    })
+}
+
+fn match_date_format(
+    input: &str,
+    date_order: DateOrder,
+    current_year: i32,
+) -> Option<(DateValue, &'static str, Vec<String>)> {
+    let mut notes = Vec::new();
+
+    if let Ok(datetime) = OffsetDateTime::parse(input, &Rfc3339) {
+        if input.ends_with("-00:00") {
+            notes.push(
+                "The offset -00:00 marks the local offset as unknown; the time was read as UTC."
+                    .to_string(),
+            );
+        }
+        return Some((DateValue::Offset(datetime), "RFC 3339", notes));
+    }
+    if let Some(format) = iso_8601_variant(input) {
+        let value = match OffsetDateTime::parse(input, &Iso8601::DEFAULT) {
+            Ok(datetime) => Some(DateValue::Offset(datetime)),
+            Err(_) if input.contains('T') => PrimitiveDateTime::parse(input, &Iso8601::DEFAULT)
+                .ok()
+                .map(DateValue::Local),
+            Err(_) => Date::parse(input, &Iso8601::DEFAULT)
+                .ok()
+                .map(DateValue::Date),
+        };
+        if let Some(value) = value {
+            return Some((value, format, notes));
+        }
+    }
+    if let Ok(datetime) = OffsetDateTime::parse(input, &Rfc2822) {
+        notes.extend(two_digit_year_note(input, datetime.year()));
+        let format = match input.contains(',') && input.ends_with(" GMT") && notes.is_empty() {
+            true => "HTTP-date (IMF-fixdate)",
+            false => "RFC 2822",
+        };
+        return Some((DateValue::Offset(datetime), format, notes));
+    }
+    if let Some(datetime) = input
+        .strip_suffix(" GMT")
+        .and_then(|rest| expand_rfc_850_year(rest, &mut notes))
+        .and_then(|rest| PrimitiveDateTime::parse(&rest, RFC_850_FORMAT).ok())
+    {
+        return Some((
+            DateValue::Offset(datetime.assume_utc()),
+            "HTTP-date (obsolete RFC 850)",
+            notes,
+        ));
+    }
+
+    let (local_part, offset) = split_offset(input);
+    let with_offset = |datetime: PrimitiveDateTime| match offset {
+        Some(offset) => DateValue::Offset(datetime.assume_offset(offset)),
+        None => DateValue::Local(datetime),
+    };
+    for (format, description) in LOCAL_FORMATS {
+        if let Ok(datetime) = PrimitiveDateTime::parse(local_part, description) {
+            return Some((with_offset(datetime), format, notes));
+        }
+    }
+    if offset.is_none() {
+        for (format, description) in DATE_FORMATS {
+            if let Ok(date) = Date::parse(local_part, description) {
+                return Some((DateValue::Date(date), format, notes));
+            }
+        }
+    }
+    if let Some((date, time, format)) = parse_numeric_date(local_part, date_order, &mut notes) {
+        let value = match time {
+            Some(time) => with_offset(PrimitiveDateTime::new(date, time)),
+            None if offset.is_none() => DateValue::Date(date),
+            None => return None,
+        };
+        return Some((value, format, notes));
+    }
+    let datetime =
+        PrimitiveDateTime::parse(&format!("{current_year} {local_part}"), SYSLOG_FORMAT).ok()?;
+    notes.push(format!(
+        "Syslog timestamps have no year, so the current year {current_year} was used."
+    ));
+    Some((with_offset(datetime), "syslog (RFC 3164)", notes))
+}
+
+/// Names the ISO 8601 variant an input looks like, or `None` if it cannot be ISO 8601.
+fn iso_8601_variant(input: &str) -> Option<&'static str> {
+    let date_part = input.split('T').next().unwrap_or_default();
+    let unsigned = date_part.trim_start_matches(['+', '-']);
+    if !unsigned.starts_with(|character: char| character.is_ascii_digit())
+        || !unsigned
+            .chars()
+            .all(|character| character.is_ascii_digit() || character == '-' || character == 'W')
+    {
+        return None;
+    }
+    let digit_count = unsigned.chars().filter(char::is_ascii_digit).count();
+    Some(
+        match (unsigned.contains('W'), unsigned.contains('-'), digit_count) {
+            (true, _, _) => "ISO 8601 week date",
+            (false, true, 7) | (false, false, 7) => "ISO 8601 ordinal date",
+            (false, false, 8) => "ISO 8601 basic format",
+            (false, true, 8) => "ISO 8601 extended format",
+            _ => return None,
+        },
+    )
+}
+
+/// Splits a trailing `Z`, `UTC`, `GMT` or numeric offset such as `+01:00`, `-0700` or
+/// `+00:53:28` off the end of a date and time.
+fn split_offset(input: &str) -> (&str, Option<UtcOffset>) {
+    for zone in [" UTC", " GMT", "Z"] {
+        if let Some(rest) = input.strip_suffix(zone) {
+            if rest.ends_with(|character: char| character.is_ascii_digit()) {
+                return (rest.trim_end(), Some(UtcOffset::UTC));
+            }
+        }
+    }
+    let Some(sign_position) = input.rfind(['+', '-']) else {
+        return (input, None);
+    };
+    let (rest, offset) = input.split_at(sign_position);
+    let digits = offset[1..].replace(':', "");
+    let has_time = rest.contains(':');
+    if !has_time
+        || !matches!(digits.len(), 4 | 6)
+        || !digits.chars().all(|character| character.is_ascii_digit())
+    {
+        return (input, None);
+    }
+    let hours: i8 = digits[..2].parse().unwrap_or_default();
+    let minutes: i8 = digits[2..4].parse().unwrap_or_default();
+    let seconds: i8 = digits
+        .get(4..)
+        .unwrap_or_default()
+        .parse()
+        .unwrap_or_default();
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    match UtcOffset::from_hms(sign * hours, sign * minutes, sign * seconds) {
+        Ok(offset) => (rest.trim_end(), Some(offset)),
+        Err(_) => (input, None),
+    }
+}
+
+/// Reads numeric dates such as `03/04/2024`, `2024/03/04` or `05.03.2024`, optionally
+/// followed by a time of day, and notes how the day and month were told apart.
+fn parse_numeric_date(
+    input: &str,
+    date_order: DateOrder,
+    notes: &mut Vec<String>,
+) -> Option<(Date, Option<Time>, &'static str)> {
+    let (date_part, time_part) = match input.split_once(' ') {
+        Some((date_part, time_part)) => (date_part, Some(time_part)),
+        None => (input, None),
+    };
+    let separator = ['/', '.', '-']
+        .into_iter()
+        .find(|separator| date_part.contains(*separator))?;
+    let parts: Vec<&str> = date_part.split(separator).collect();
+    let [first, second, third] = parts.as_slice() else {
+        return None;
+    };
+    let numbers: Vec<u16> = [first, second, third]
+        .iter()
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let (year, month, day, format) = if first.len() == 4 {
+        (numbers[0], numbers[1], numbers[2], "numeric year/month/day")
+    } else if third.len() == 4 {
+        let day_first = if separator == '.' {
+            notes.push("Dates with dots are read as day.month.year.".to_string());
+            true
+        } else if numbers[0] > 12 {
+            notes.push(format!(
+                "{first} cannot be a month, so the date was read as day/month/year."
+            ));
+            true
+        } else if numbers[1] > 12 {
+            notes.push(format!(
+                "{second} cannot be a month, so the date was read as month/day/year."
+            ));
+            false
+        } else if numbers[0] == numbers[1] {
+            false
+        } else {
+            notes.push(format!(
+                "Both {first} and {second} could be the month; the date was read as {} as set in Ambiguous Numeric Dates.",
+                date_order.pattern()
+            ));
+            date_order == DateOrder::DayFirst
+        };
+        match day_first {
+            true => (numbers[2], numbers[1], numbers[0], "numeric day/month/year"),
+            false => (numbers[2], numbers[0], numbers[1], "numeric month/day/year"),
+        }
+    } else {
+        return None;
+    };
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    let date = Date::from_calendar_date(i32::from(year), month, u8::try_from(day).ok()?).ok()?;
+    let time = match time_part {
+        Some(time_part) => Some(Time::parse(time_part, TIME_OF_DAY_FORMAT).ok()?),
+        None => None,
+    };
+    Some((date, time, format))
+}
+
+/// RFC 850 dates such as `Sunday, 06-Nov-94 08:49:37` carry a two-digit year.
+fn expand_rfc_850_year(input: &str, notes: &mut Vec<String>) -> Option<String> {
+    let (weekday, rest) = input.split_once(", ")?;
+    let (date, time) = rest.split_once(' ')?;
+    let (day_month, year) = date.rsplit_once('-')?;
+    if year.len() != 2 {
+        return Some(input.to_string());
+    }
+    let year: i32 = year.parse().ok()?;
+    let full_year = match year < 50 {
+        true => 2000 + year,
+        false => 1900 + year,
+    };
+    notes.push(format!(
+        "The two-digit year {year:02} was read as {full_year} (00-49 are 20xx, 50-99 are 19xx)."
+    ));
+    Some(format!("{weekday}, {day_month}-{full_year} {time}"))
+}
+
+fn two_digit_year_note(input: &str, year: i32) -> Option<String> {
+    let tokens: Vec<&str> = input
+        .split([' ', ','])
+        .filter(|token| !token.is_empty())
+        .collect();
+    tokens.windows(2).find_map(|pair| {
+        let is_two_digit_year = pair[0].len() == 2
+            && pair[0].chars().all(|character| character.is_ascii_digit())
+            && pair[1].contains(':');
+        is_two_digit_year.then(|| {
+            format!(
+                "The two-digit year {} was read as {year} (00-49 are 20xx, 50-99 are 19xx).",
+                pair[0]
+            )
+        })
+    })
+}
+
+/// Applies the time zone to a date and time without an offset, noting daylight saving gaps
+/// and overlaps.
+fn assume_time_zone(
+    datetime: PrimitiveDateTime,
+    time_zone: &'static Tz,
+    notes: &mut Vec<String>,
+) -> OffsetDateTime {
+    let name = time_zone.name();
+    notes.push(format!(
+        "No offset given, so the time was read as local time in {name}."
+    ));
+    match datetime.assume_timezone(time_zone) {
+        OffsetResult::Some(datetime) => datetime,
+        OffsetResult::Ambiguous(earlier, _) => {
+            notes.push(format!(
+                "This time occurs twice in {name} because clocks were turned back; the earlier one was used."
+            ));
+            earlier
+        }
+        OffsetResult::None => {
+            notes.push(format!(
+                "This time does not exist in {name} because clocks were turned forward; the offset in effect at that UTC time was used."
+            ));
+            datetime.assume_timezone_utc(time_zone)
+        }
+    }
+}
+
+/// Notes a weekday name in the input that disagrees with the date, which parsers ignore.
+fn weekday_mismatch(input: &str, date: Date) -> Option<String> {
+    let actual = date.weekday();
+    let named = input
+        .split(|character: char| !character.is_ascii_alphabetic())
+        .filter(|word| word.len() >= 3)
+        .find_map(|word| {
+            let word = word.to_lowercase();
+            std::iter::successors(Some(Weekday::Monday), |weekday| Some(weekday.next()))
+                .take(7)
+                .find(|weekday| {
+                    let name = weekday.to_string().to_lowercase();
+                    name == word || name[..3] == word
+                })
+        })?;
+    (named != actual).then(|| {
+        format!("The input names a {named}, but the date is a {actual}; the date was kept.")
+    })
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+
+/// The start of GPS time, 1980-01-06 00:00:00 UTC, as a Unix timestamp.
+const GPS_EPOCH: i64 = 315_964_800;
+
+/// The UTC instants from which each leap second since the GPS epoch applies. GPS time does
+/// not count leap seconds, so it runs one second further ahead of UTC after each of them.
+const LEAP_SECONDS: [i64; 18] = [
+    datetime!(1981-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1982-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1983-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1985-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1988-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(1990-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(1991-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(1992-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1993-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1994-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1996-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(1997-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(1999-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(2006-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(2009-01-01 0:00 UTC).unix_timestamp(),
+    datetime!(2012-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(2015-07-01 0:00 UTC).unix_timestamp(),
+    datetime!(2017-01-01 0:00 UTC).unix_timestamp(),
+];
+
+/// A count of units since an epoch, as used by operating systems, runtimes and file formats.
+#[derive(Copy, Clone, Debug, EnumIter, PartialEq)]
+enum TimestampKind {
+    Unix,
+    FileTime,
+    DotNetTicks,
+    Cocoa,
+    Gps,
+    Ntp,
+    SerialDate,
+    JulianDay,
+    ObjectId,
+}
+
+impl TimestampKind {
+    fn label(&self, unix_unit: TimestampUnit) -> String {
+        match self {
+            Self::Unix => format!("Unix Timestamp ({})", unix_unit.name()),
+            Self::FileTime => "Windows FILETIME (100 ns intervals since 1601)".to_string(),
+            Self::DotNetTicks => ".NET Ticks (100 ns intervals since 0001)".to_string(),
+            Self::Cocoa => "Apple Cocoa / Core Data (seconds since 2001)".to_string(),
+            Self::Gps => "GPS Time (seconds since 1980-01-06, without leap seconds)".to_string(),
+            Self::Ntp => "NTP (seconds since 1900)".to_string(),
+            Self::SerialDate => {
+                "Excel / LibreOffice Serial Date (local days since 1899-12-30)".to_string()
+            }
+            Self::JulianDay => "Julian Day".to_string(),
+            Self::ObjectId => "MongoDB ObjectId (creation time)".to_string(),
+        }
+    }
+
+    /// What one timestamp counts, completing the sentence "Read as ...".
+    fn description(&self, unix_unit: TimestampUnit) -> String {
+        match self {
+            Self::Unix => format!("{} since 1970-01-01 00:00 UTC", unix_unit.name()),
+            Self::FileTime => "100-nanosecond intervals since 1601-01-01 00:00 UTC".to_string(),
+            Self::DotNetTicks => "100-nanosecond intervals since 0001-01-01 00:00 UTC".to_string(),
+            Self::Cocoa => "seconds since 2001-01-01 00:00 UTC".to_string(),
+            Self::Gps => "GPS seconds since 1980-01-06 00:00 UTC".to_string(),
+            Self::Ntp => "seconds since 1900-01-01 00:00 UTC (NTP era 0)".to_string(),
+            Self::SerialDate => "days since 1899-12-30 00:00 local time".to_string(),
+            Self::JulianDay => "days since -4713-11-24 12:00 UTC".to_string(),
+            Self::ObjectId => "seconds since 1970-01-01 00:00 UTC in the first 4 bytes".to_string(),
+        }
+    }
+
+    /// The epoch as a Unix timestamp and the length of one counted unit in nanoseconds.
+    fn epoch_and_unit(&self, unix_unit: TimestampUnit) -> (i64, i128) {
+        match self {
+            Self::Unix => (0, unix_unit.nanoseconds()),
+            Self::FileTime => (-11_644_473_600, 100),
+            Self::DotNetTicks => (-62_135_596_800, 100),
+            Self::Cocoa => (978_307_200, NANOS_PER_SECOND),
+            Self::Gps => (GPS_EPOCH, NANOS_PER_SECOND),
+            Self::Ntp => (-2_208_988_800, NANOS_PER_SECOND),
+            Self::SerialDate => (-2_209_161_600, NANOS_PER_DAY),
+            Self::JulianDay => (-210_866_760_000, NANOS_PER_DAY),
+            Self::ObjectId => (0, NANOS_PER_SECOND),
+        }
+    }
+
+    fn format(&self, datetime: OffsetDateTime, unix_unit: TimestampUnit) -> String {
+        let (epoch, unit) = self.epoch_and_unit(unix_unit);
+        let nanoseconds = match self {
+            // Serial dates count local days, like the cells of a spreadsheet.
+            Self::SerialDate => datetime
+                .replace_offset(UtcOffset::UTC)
+                .unix_timestamp_nanos(),
+            Self::Gps => {
+                let leap_seconds = LEAP_SECONDS
+                    .iter()
+                    .filter(|leap_second| datetime.unix_timestamp() >= **leap_second)
+                    .count();
+                datetime.unix_timestamp_nanos() + leap_seconds as i128 * NANOS_PER_SECOND
+            }
+            _ => datetime.unix_timestamp_nanos(),
+        };
+        let count = nanoseconds - i128::from(epoch) * NANOS_PER_SECOND;
+        match self {
+            Self::ObjectId => match u32::try_from(count.div_euclid(unit)) {
+                Ok(seconds) => format!("{seconds:08x}0000000000000000"),
+                Err(_) => "Not representable: ObjectIds hold times from 1970 to 2106".to_string(),
+            },
+            _ => format_scaled(count, unit),
+        }
+    }
+
+    /// Reads a timestamp, returning the date and notes on how it was read.
+    fn parse(
+        &self,
+        input: &str,
+        unix_unit: TimestampUnit,
+        time_zone: &'static Tz,
+    ) -> Result<(OffsetDateTime, Vec<String>), String> {
+        let (epoch, unit) = self.epoch_and_unit(unix_unit);
+        let mut notes = vec![format!("Read as {}.", self.description(unix_unit))];
+        let input: String = input
+            .chars()
+            .filter(|character| !character.is_whitespace() && *character != '_')
+            .collect();
+        let count = match self {
+            Self::ObjectId => i128::from(parse_object_id(&input, &mut notes)?) * unit,
+            _ => parse_scaled(&input, unit)
+                .ok_or_else(|| format!("\"{input}\" is not a decimal number."))?,
+        };
+        let out_of_range =
+            || "The timestamp is outside the supported years -9999 to 9999.".to_string();
+        let mut nanoseconds = (i128::from(epoch) * NANOS_PER_SECOND)
+            .checked_add(count)
+            .ok_or_else(out_of_range)?;
+        if *self == Self::Gps {
+            let seconds = nanoseconds.div_euclid(NANOS_PER_SECOND);
+            let mut leap_seconds = 0;
+            for leap_second in LEAP_SECONDS {
+                if seconds - (leap_seconds + 1) >= i128::from(leap_second) {
+                    leap_seconds += 1;
+                }
+            }
+            nanoseconds -= leap_seconds * NANOS_PER_SECOND;
+            notes.push(format!(
+                "GPS time was {leap_seconds} seconds ahead of UTC at this date because of leap seconds."
+            ));
+        }
+        let datetime =
+            OffsetDateTime::from_unix_timestamp_nanos(nanoseconds).map_err(|_| out_of_range())?;
+        match self {
+            Self::SerialDate => {
+                if (0..61 * NANOS_PER_DAY).contains(&count) {
+                    notes.push(
+                        "Excel counts a nonexistent 1900-02-29 as serial 60, so it shows serial dates below 61 one day later."
+                            .to_string(),
+                    );
+                }
+                let local = PrimitiveDateTime::new(datetime.date(), datetime.time());
+                Ok((assume_time_zone(local, time_zone, &mut notes), notes))
+            }
+            _ => Ok((datetime, notes)),
+        }
+    }
+}
+
+/// Reads the creation time in seconds from a 24-digit ObjectId, or from its first 8 digits.
+fn parse_object_id(input: &str, notes: &mut Vec<String>) -> Result<u32, String> {
+    let hex = input
+        .strip_prefix("ObjectId(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(input)
+        .trim_matches(['"', '\'']);
+    if !matches!(hex.len(), 8 | 24) || !hex.chars().all(|character| character.is_ascii_hexdigit()) {
+        return Err(
+            "An ObjectId is 24 hexadecimal digits, of which the first 8 are the creation time."
+                .to_string(),
+        );
+    }
+    if hex.len() == 24 {
+        notes.push(
+            "Only the first 8 digits hold the time; the other 16 are a random value and a counter."
+                .to_string(),
+        );
+    }
+    u32::from_str_radix(&hex[..8], 16).map_err(|err| err.to_string())
+}
+
+/// Reads a decimal such as `-12.5` as a number of nanoseconds, where `unit` is the length of
+/// one whole in nanoseconds. Digits finer than a nanosecond are rounded.
+fn parse_scaled(input: &str, unit: i128) -> Option<i128> {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|character| character.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: i128 = match whole {
+        "" => 0,
+        whole => whole.parse().ok()?,
+    };
+    let fraction = &fraction[..fraction.len().min(18)];
+    let fraction = match fraction {
+        "" => 0,
+        fraction => {
+            let scale = 10_i128.pow(fraction.len() as u32);
+            (fraction.parse::<i128>().ok()? * unit + scale / 2) / scale
+        }
+    };
+    let magnitude = whole.checked_mul(unit)?.checked_add(fraction)?;
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Writes a number of nanoseconds as a decimal count of `unit`, rounded to the nanosecond and
+/// without trailing zeros.
+fn format_scaled(nanoseconds: i128, unit: i128) -> String {
+    let sign = if nanoseconds < 0 { "-" } else { "" };
+    let magnitude = nanoseconds.unsigned_abs();
+    let unit = unit.unsigned_abs();
+    let mut whole = magnitude / unit;
+    let remainder = magnitude % unit;
+    if remainder == 0 {
+        return format!("{sign}{whole}");
+    }
+    // Enough digits for one nanosecond to change the last one.
+    let digits = (unit - 1).ilog10() + 1;
+    let scale = 10_u128.pow(digits);
+    let mut fraction = (remainder * scale + unit / 2) / unit;
+    if fraction == scale {
+        whole += 1;
+        fraction = 0;
+    }
+    let fraction = format!("{fraction:0width$}", width = digits as usize);
+    match fraction.trim_end_matches('0') {
+        "" => format!("{sign}{whole}"),
+        fraction => format!("{sign}{whole}.{fraction}"),
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, StrumDisplay, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum TimestampUnit {
+    #[default]
+    #[strum(to_string = "Auto-detect")]
+    Auto,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl TimestampUnit {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Auto | Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    fn nanoseconds(&self) -> i128 {
+        match self {
+            Self::Auto | Self::Seconds => NANOS_PER_SECOND,
+            Self::Milliseconds => 1_000_000,
+            Self::Microseconds => 1_000,
+            Self::Nanoseconds => 1,
+        }
+    }
+
+    /// Guesses the unit of a Unix timestamp from the digits before the decimal point: up to 11
+    /// digits are seconds (until the year 5138), then milliseconds, microseconds and
+    /// nanoseconds in steps of three digits.
+    fn detect(input: &str) -> Self {
+        let whole = input.split('.').next().unwrap_or_default();
+        let digit_count = whole
+            .chars()
+            .filter(char::is_ascii_digit)
+            .skip_while(|digit| *digit == '0')
+            .count();
+        match digit_count {
+            0..=11 => Self::Seconds,
+            12..=14 => Self::Milliseconds,
+            15..=17 => Self::Microseconds,
+            _ => Self::Nanoseconds,
+        }
+    }
+}
+
+impl SelectFormEnum for TimestampUnit {}
+
+impl From<TimestampUnit> for String {
+    fn from(unit: TimestampUnit) -> Self {
+        unit.to_string()
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, StrumDisplay, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum DateOrder {
+    #[default]
+    #[strum(to_string = "Month first (MM/DD/YYYY)")]
+    MonthFirst,
+    #[strum(to_string = "Day first (DD/MM/YYYY)")]
+    DayFirst,
+}
+
+impl DateOrder {
+    fn pattern(&self) -> &'static str {
+        match self {
+            Self::MonthFirst => "month/day/year",
+            Self::DayFirst => "day/month/year",
+        }
+    }
+}
+
+impl SelectFormEnum for DateOrder {}
+
+impl From<DateOrder> for String {
+    fn from(date_order: DateOrder) -> Self {
+        date_order.to_string()
     }
 }
 
 #[derive(Debug, Clone, Copy, Eq)]
-enum DcTimeZone {
+pub(crate) enum DcTimeZone {
     Base(&'static Tz),
 }
 
@@ -218,7 +1322,7 @@ impl From<DcTimeZone> for String {
 }
 
 #[derive(Debug, Clone)]
-struct TzParseError;
+pub(crate) struct TzParseError;
 
 impl Display for TzParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -252,7 +1356,7 @@ impl From<DcTimeZone> for &'static str {
 impl SelectFormEnum for DcTimeZone {}
 
 impl DcTimeZone {
-    fn inner(&self) -> &'static Tz {
+    pub(crate) fn inner(&self) -> &'static Tz {
         match self {
             Self::Base(tz) => tz,
         }
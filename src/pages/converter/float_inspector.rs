@@ -0,0 +1,576 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaMicroscope;
+use half::{bf16, f16};
+use num_bigint::BigInt;
+use std::fmt;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::inputs::{SelectForm, SelectFormEnum, TextInput};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "IEEE 754 Float Inspector",
+    short_title: "Float Inspector",
+    description: "Inspect the sign, exponent and mantissa of f16, bf16, f32 and f64 values",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaMicroscope> = WidgetIcon { icon: FaMicroscope };
+
+pub fn FloatInspector() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(FloatValue {
+            bits: 0.1f32.to_bits() as u64,
+            editing: None,
+            error: None,
+        })
+    });
+    let mut format = use_context_provider(|| Signal::new(FloatFormat::default()));
+    let error = value_context.read().error.clone();
+
+    let current_format = format();
+    let bits = value_context.read().bits;
+    let float = current_format.decode(bits);
+
+    rsx! {
+        div { class: "widget-grid",
+            div { class: "widget-params",
+                SelectForm::<FloatFormat> {
+                    label: "Format",
+                    oninput: move |new_format: FloatFormat| {
+                        // Converting through f64 is exact for every narrower format.
+                        let value = current_format.to_f64(value_context.read().bits);
+                        value_context
+                            .with_mut(|value_state| {
+                                value_state.bits = new_format.round_f64(value);
+                                value_state.editing = None;
+                                value_state.error = None;
+                            });
+                        format.set(new_format);
+                    },
+                    value: current_format,
+                }
+            }
+            float_input { field: FloatField::Decimal }
+            float_input { field: FloatField::Hexadecimal }
+            float_input { field: FloatField::Binary }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            div { class: "widget-buttons flex-wrap gap-y-2",
+                for special in SpecialValue::ALL {
+                    button {
+                        class: "btn btn-info btn-sm",
+                        onclick: move |_| {
+                            value_context
+                                .with_mut(|value| {
+                                    value.bits = special.bits(format());
+                                    value.editing = None;
+                                    value.error = None;
+                                });
+                        },
+                        "{special}"
+                    }
+                }
+            }
+            div { class: "widget-params",
+                TextInput {
+                    label: "Class",
+                    value: float.classification(),
+                    readonly: true,
+                }
+                TextInput { label: "Sign", value: float.sign_description(), readonly: true }
+                TextInput {
+                    label: "Exponent",
+                    value: float.exponent_description(),
+                    readonly: true,
+                }
+                TextInput {
+                    label: "Mantissa",
+                    value: float.mantissa_description(),
+                    readonly: true,
+                }
+            }
+            TextInput {
+                label: "Exact Stored Value",
+                value: float.exact_decimal().unwrap_or_else(|| "Not a finite number".to_string()),
+                readonly: true,
+            }
+            div { class: "widget-params",
+                TextInput {
+                    label: "Next Down",
+                    value: current_format.describe_neighbour(float.next_down()),
+                    readonly: true,
+                }
+                TextInput {
+                    label: "Next Up",
+                    value: current_format.describe_neighbour(float.next_up()),
+                    readonly: true,
+                }
+                TextInput {
+                    label: "ULP (Unit in the Last Place)",
+                    value: float.ulp_description().unwrap_or_else(|| "Not a finite number".to_string()),
+                    readonly: true,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn float_input(field: FloatField) -> Element {
+    let mut value_context = use_context::<Signal<FloatValue>>();
+    let format = use_context::<Signal<FloatFormat>>();
+
+    // The field being edited keeps its raw text so that partial input is not reformatted.
+    let value = value_context.with(|value| match &value.editing {
+        Some((editing_field, input)) if *editing_field == field => input.clone(),
+        _ => field.format(format(), value.bits),
+    });
+
+    rsx! {
+        TextInput {
+            label: "{field}",
+            value: "{value}",
+            oninput: move |event: Event<FormData>| {
+                let input = event.value();
+                value_context
+                    .with_mut(|value| {
+                        match field.parse(format(), input.trim()) {
+                            Ok(bits) => {
+                                value.bits = bits;
+                                value.error = None;
+                            }
+                            Err(err) => value.error = Some(err),
+                        }
+                        value.editing = Some((field, input));
+                    });
+            },
+            onchange: move |_| {
+                value_context
+                    .with_mut(|value| {
+                        value.editing = None;
+                        value.error = None;
+                    });
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+struct FloatValue {
+    bits: u64,
+    editing: Option<(FloatField, String)>,
+    error: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum FloatField {
+    Decimal,
+    Hexadecimal,
+    Binary,
+}
+
+impl fmt::Display for FloatField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            Self::Decimal => "Value (decimal, inf or nan)",
+            Self::Hexadecimal => "Bit Pattern (hex)",
+            Self::Binary => "Bit Pattern (sign exponent mantissa)",
+        };
+        write!(f, "{label}")
+    }
+}
+
+impl FloatField {
+    fn format(&self, format: FloatFormat, bits: u64) -> String {
+        let float = format.decode(bits);
+        match self {
+            Self::Decimal => format.shortest_decimal(bits),
+            Self::Hexadecimal => format!(
+                "0x{bits:0width$X}",
+                width = format.total_bits() as usize / 4
+            ),
+            Self::Binary => format!(
+                "{} {:0exponent_width$b} {:0mantissa_width$b}",
+                u8::from(float.negative),
+                float.exponent,
+                float.mantissa,
+                exponent_width = format.exponent_bits() as usize,
+                mantissa_width = format.mantissa_bits() as usize,
+            ),
+        }
+    }
+
+    fn parse(&self, format: FloatFormat, input: &str) -> Result<u64, String> {
+        match self {
+            Self::Decimal => format
+                .parse_decimal(input)
+                .ok_or_else(|| format!("\"{input}\" is not a decimal number, inf or nan.")),
+            Self::Hexadecimal => parse_bit_pattern(
+                input.trim_start_matches("0x").trim_start_matches("0X"),
+                16,
+                format,
+            ),
+            Self::Binary => parse_bit_pattern(
+                input.trim_start_matches("0b").trim_start_matches("0B"),
+                2,
+                format,
+            ),
+        }
+    }
+}
+
+fn parse_bit_pattern(digits: &str, radix: u32, format: FloatFormat) -> Result<u64, String> {
+    let digits = digits.replace([' ', '_', '|'], "");
+    let bits = u64::from_str_radix(&digits, radix)
+        .map_err(|_| format!("\"{digits}\" is not a base {radix} bit pattern."))?;
+    match bits >> (format.total_bits() - 1) >> 1 {
+        0 => Ok(bits),
+        _ => Err(format!(
+            "\"{digits}\" does not fit in the {} bits of {format}.",
+            format.total_bits()
+        )),
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum FloatFormat {
+    #[strum(to_string = "f16 (IEEE 754 half precision)")]
+    Half,
+    #[strum(to_string = "bf16 (bfloat16)")]
+    BFloat16,
+    #[default]
+    #[strum(to_string = "f32 (IEEE 754 single precision)")]
+    Single,
+    #[strum(to_string = "f64 (IEEE 754 double precision)")]
+    Double,
+}
+
+impl SelectFormEnum for FloatFormat {}
+
+impl From<FloatFormat> for String {
+    fn from(format: FloatFormat) -> Self {
+        format.to_string()
+    }
+}
+
+impl FloatFormat {
+    fn exponent_bits(&self) -> u32 {
+        match self {
+            Self::Half => 5,
+            Self::BFloat16 | Self::Single => 8,
+            Self::Double => 11,
+        }
+    }
+
+    fn mantissa_bits(&self) -> u32 {
+        match self {
+            Self::Half => 10,
+            Self::BFloat16 => 7,
+            Self::Single => 23,
+            Self::Double => 52,
+        }
+    }
+
+    fn total_bits(&self) -> u32 {
+        1 + self.exponent_bits() + self.mantissa_bits()
+    }
+
+    fn bias(&self) -> i64 {
+        (1 << (self.exponent_bits() - 1)) - 1
+    }
+
+    fn max_exponent(&self) -> u64 {
+        (1 << self.exponent_bits()) - 1
+    }
+
+    fn decode(&self, bits: u64) -> DecodedFloat {
+        DecodedFloat {
+            format: *self,
+            negative: bits >> (self.total_bits() - 1) & 1 == 1,
+            exponent: bits >> self.mantissa_bits() & self.max_exponent(),
+            mantissa: bits & ((1 << self.mantissa_bits()) - 1),
+        }
+    }
+
+    fn to_f64(self, bits: u64) -> f64 {
+        match self {
+            Self::Half => f16::from_bits(bits as u16).to_f64(),
+            Self::BFloat16 => bf16::from_bits(bits as u16).to_f64(),
+            Self::Single => f64::from(f32::from_bits(bits as u32)),
+            Self::Double => f64::from_bits(bits),
+        }
+    }
+
+    /// Rounds to the nearest representable value, ties to even.
+    fn round_f64(&self, value: f64) -> u64 {
+        match self {
+            Self::Half => u64::from(f16::from_f64(value).to_bits()),
+            Self::BFloat16 => u64::from(bf16::from_f64(value).to_bits()),
+            Self::Single => u64::from((value as f32).to_bits()),
+            Self::Double => value.to_bits(),
+        }
+    }
+
+    /// Parses decimal or scientific notation, `inf`, `infinity`, `∞` and `nan`. f32 and f64
+    /// round straight from the decimal; f16 and bf16 round from the nearest f64, which can
+    /// differ from direct rounding only for inputs within 2^-53 of a tie.
+    fn parse_decimal(&self, input: &str) -> Option<u64> {
+        let input = input.replace(['_', ','], "").replace('∞', "inf");
+        match self {
+            Self::Single => input
+                .parse::<f32>()
+                .ok()
+                .map(|value| u64::from(value.to_bits())),
+            _ => input.parse::<f64>().ok().map(|value| self.round_f64(value)),
+        }
+    }
+
+    /// The shortest decimal that reads back as the same bit pattern.
+    fn shortest_decimal(&self, bits: u64) -> String {
+        let value = self.to_f64(bits);
+        if value.is_nan() {
+            return "NaN".to_string();
+        }
+        if value.is_infinite() {
+            return match value.is_sign_negative() {
+                true => "-inf".to_string(),
+                false => "inf".to_string(),
+            };
+        }
+        let digits = (1..=17)
+            .map(|precision| format!("{:.*e}", precision - 1, value))
+            .find(|candidate| self.parse_decimal(candidate) == Some(bits))
+            .unwrap_or_else(|| format!("{value:e}"));
+        let shortest = digits.parse::<f64>().unwrap_or(value);
+        match shortest.abs() {
+            magnitude if magnitude != 0.0 && !(1e-5..1e16).contains(&magnitude) => {
+                format!("{shortest:e}")
+            }
+            _ => format!("{shortest}"),
+        }
+    }
+
+    fn describe_neighbour(&self, bits: Option<u64>) -> String {
+        match bits {
+            Some(bits) => format!(
+                "{} (0x{bits:0width$X})",
+                self.shortest_decimal(bits),
+                width = self.total_bits() as usize / 4
+            ),
+            None => "None".to_string(),
+        }
+    }
+}
+
+struct DecodedFloat {
+    format: FloatFormat,
+    negative: bool,
+    exponent: u64,
+    mantissa: u64,
+}
+
+impl DecodedFloat {
+    fn bits(&self) -> u64 {
+        u64::from(self.negative) << (self.format.total_bits() - 1)
+            | self.exponent << self.format.mantissa_bits()
+            | self.mantissa
+    }
+
+    fn is_finite(&self) -> bool {
+        self.exponent != self.format.max_exponent()
+    }
+
+    fn is_nan(&self) -> bool {
+        !self.is_finite() && self.mantissa != 0
+    }
+
+    /// The power of two of the last mantissa bit; subnormals share the smallest normal exponent.
+    fn ulp_exponent(&self) -> i64 {
+        self.exponent.max(1) as i64 - self.format.bias() - i64::from(self.format.mantissa_bits())
+    }
+
+    fn classification(&self) -> String {
+        let quiet_bit = 1 << (self.format.mantissa_bits() - 1);
+        match (self.exponent, self.mantissa) {
+            (0, 0) => "Zero".to_string(),
+            (0, _) => "Subnormal".to_string(),
+            (exponent, 0) if exponent == self.format.max_exponent() => "Infinity".to_string(),
+            (exponent, mantissa) if exponent == self.format.max_exponent() => {
+                let kind = match mantissa & quiet_bit {
+                    0 => "Signaling NaN",
+                    _ => "Quiet NaN",
+                };
+                format!("{kind}, payload 0x{:X}", mantissa & !quiet_bit)
+            }
+            _ => "Normal".to_string(),
+        }
+    }
+
+    fn sign_description(&self) -> String {
+        match self.negative {
+            true => "1 (negative)".to_string(),
+            false => "0 (positive)".to_string(),
+        }
+    }
+
+    fn exponent_description(&self) -> String {
+        let width = self.format.exponent_bits() as usize;
+        let meaning = match self.exponent {
+            0 => format!("subnormal, 2^{}", 1 - self.format.bias()),
+            exponent if exponent == self.format.max_exponent() => "infinity or NaN".to_string(),
+            exponent => format!("2^{}", exponent as i64 - self.format.bias()),
+        };
+        format!("{:0width$b} = {} ({meaning})", self.exponent, self.exponent)
+    }
+
+    fn mantissa_description(&self) -> String {
+        let implicit_bit = match self.exponent {
+            0 => 0,
+            _ => 1,
+        };
+        format!(
+            "{implicit_bit}.{:0width$b} (0x{:X})",
+            self.mantissa,
+            self.mantissa,
+            width = self.format.mantissa_bits() as usize
+        )
+    }
+
+    /// The exact value as a decimal; every finite binary float has a terminating expansion.
+    fn exact_decimal(&self) -> Option<String> {
+        if !self.is_finite() {
+            return None;
+        }
+        let significand = match self.exponent {
+            0 => self.mantissa,
+            _ => self.mantissa | 1 << self.format.mantissa_bits(),
+        };
+        let sign = match self.negative {
+            true => "-",
+            false => "",
+        };
+        Some(format!(
+            "{sign}{}",
+            exact_power_of_two_multiple(BigInt::from(significand), self.ulp_exponent())
+        ))
+    }
+
+    fn ulp_description(&self) -> Option<String> {
+        if !self.is_finite() {
+            return None;
+        }
+        let exponent = self.ulp_exponent();
+        Some(format!(
+            "2^{exponent} = {}",
+            exact_power_of_two_multiple(BigInt::from(1), exponent)
+        ))
+    }
+
+    /// The next representable value towards positive infinity, if any.
+    fn next_up(&self) -> Option<u64> {
+        let bits = self.bits();
+        let sign_bit = 1 << (self.format.total_bits() - 1);
+        if self.is_nan() || bits == self.format.max_exponent() << self.format.mantissa_bits() {
+            return None;
+        }
+        Some(match (self.negative, bits & !sign_bit) {
+            (_, 0) => 1,
+            (true, _) => bits - 1,
+            (false, _) => bits + 1,
+        })
+    }
+
+    /// The next representable value towards negative infinity, if any.
+    fn next_down(&self) -> Option<u64> {
+        let sign_bit = 1 << (self.format.total_bits() - 1);
+        let negated = self.format.decode(self.bits() ^ sign_bit);
+        negated.next_up().map(|bits| bits ^ sign_bit)
+    }
+}
+
+/// Writes `value * 2^exponent` exactly, using `2^-n = 5^n / 10^n` for negative exponents.
+fn exact_power_of_two_multiple(value: BigInt, exponent: i64) -> String {
+    if exponent >= 0 {
+        return (value << exponent as u64).to_string();
+    }
+    let fraction_digits = exponent.unsigned_abs() as usize;
+    let digits = (value * BigInt::from(5).pow(fraction_digits as u32)).to_string();
+    let digits = format!("{digits:0>width$}", width = fraction_digits + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - fraction_digits);
+    let fraction = fraction.trim_end_matches('0');
+    match fraction.is_empty() {
+        true => integer.to_string(),
+        false => format!("{integer}.{fraction}"),
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum SpecialValue {
+    Zero,
+    NegativeZero,
+    Infinity,
+    NegativeInfinity,
+    QuietNan,
+    SmallestSubnormal,
+    SmallestNormal,
+    Largest,
+    One,
+    Epsilon,
+}
+
+impl fmt::Display for SpecialValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            Self::Zero => "+0",
+            Self::NegativeZero => "-0",
+            Self::Infinity => "+∞",
+            Self::NegativeInfinity => "-∞",
+            Self::QuietNan => "NaN",
+            Self::SmallestSubnormal => "Smallest Subnormal",
+            Self::SmallestNormal => "Smallest Normal",
+            Self::Largest => "Largest Finite",
+            Self::One => "1",
+            Self::Epsilon => "Machine Epsilon",
+        };
+        write!(f, "{label}")
+    }
+}
+
+impl SpecialValue {
+    const ALL: [Self; 10] = [
+        Self::Zero,
+        Self::NegativeZero,
+        Self::Infinity,
+        Self::NegativeInfinity,
+        Self::QuietNan,
+        Self::SmallestSubnormal,
+        Self::SmallestNormal,
+        Self::Largest,
+        Self::One,
+        Self::Epsilon,
+    ];
+
+    fn bits(&self, format: FloatFormat) -> u64 {
+        let sign_bit = 1 << (format.total_bits() - 1);
+        let mantissa_bits = format.mantissa_bits();
+        let infinity = format.max_exponent() << mantissa_bits;
+        let one = (format.bias() as u64) << mantissa_bits;
+        match self {
+            Self::Zero => 0,
+            Self::NegativeZero => sign_bit,
+            Self::Infinity => infinity,
+            Self::NegativeInfinity => sign_bit | infinity,
+            Self::QuietNan => infinity | 1 << (mantissa_bits - 1),
+            Self::SmallestSubnormal => 1,
+            Self::SmallestNormal => 1 << mantissa_bits,
+            Self::Largest => infinity - 1,
+            Self::One => one,
+            Self::Epsilon => one - (u64::from(mantissa_bits) << mantissa_bits),
+        }
+    }
+}
@@ -0,0 +1,311 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaGlobe;
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use cidr::IpCidr;
+
+use crate::components::inputs::TextInput;
+use crate::pages::encoder_decoder::cidr_decoder::{
+    address_from_u128, address_to_u128, expanded_ipv6, reverse_dns_zone,
+};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "IP Address Format Converter",
+    short_title: "IP Format",
+    description:
+        "Convert IP addresses between dotted, integer, hex, octal, binary and IPv6 notations",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaGlobe> = WidgetIcon { icon: FaGlobe };
+
+pub fn IpFormatConverter() -> Element {
+    let value_context = use_context_provider(|| {
+        Signal::new(ConverterValue {
+            address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)),
+            editing: None,
+            error: None,
+        })
+    });
+    let error = value_context.read().error.clone();
+
+    rsx! {
+        div { class: "widget-grid",
+            converter_input { format: IpFormat::Address }
+            converter_input { format: IpFormat::Integer }
+            converter_input { format: IpFormat::Hexadecimal }
+            converter_input { format: IpFormat::Binary }
+            converter_input { format: IpFormat::DottedOctal }
+            converter_input { format: IpFormat::DottedHexadecimal }
+            converter_input { format: IpFormat::Ipv4Mapped }
+            converter_input { format: IpFormat::ExpandedIpv6 }
+            converter_input { format: IpFormat::PtrName }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn converter_input(format: IpFormat) -> Element {
+    let mut value_context = use_context::<Signal<ConverterValue>>();
+
+    // The field being edited keeps its raw text so that partial input is not reformatted.
+    let value = value_context.with(|value| match &value.editing {
+        Some((editing_format, input)) if *editing_format == format => input.clone(),
+        _ => format.format(value.address).unwrap_or_default(),
+    });
+
+    rsx! {
+        TextInput {
+            label: "{format}",
+            value: "{value}",
+            oninput: move |event: Event<FormData>| {
+                let input = event.value();
+                value_context
+                    .with_mut(|value| {
+                        match format.parse(input.trim()) {
+                            Ok(address) => {
+                                value.address = address.to_canonical();
+                                value.error = None;
+                            }
+                            Err(err) => value.error = Some(err),
+                        }
+                        value.editing = Some((format, input));
+                    });
+            },
+            onchange: move |_| {
+                value_context
+                    .with_mut(|value| {
+                        value.editing = None;
+                        value.error = None;
+                    });
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ConverterValue {
+    address: IpAddr,
+    editing: Option<(IpFormat, String)>,
+    error: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum IpFormat {
+    Address,
+    Integer,
+    Hexadecimal,
+    Binary,
+    DottedOctal,
+    DottedHexadecimal,
+    Ipv4Mapped,
+    ExpandedIpv6,
+    PtrName,
+}
+
+impl fmt::Display for IpFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            Self::Address => "Address (any notation)",
+            Self::Integer => "Integer",
+            Self::Hexadecimal => "Hexadecimal",
+            Self::Binary => "Binary",
+            Self::DottedOctal => "Dotted Octal (IPv4)",
+            Self::DottedHexadecimal => "Dotted Hexadecimal (IPv4)",
+            Self::Ipv4Mapped => "IPv4-Mapped IPv6",
+            Self::ExpandedIpv6 => "Expanded IPv6",
+            Self::PtrName => "PTR Name",
+        };
+        write!(f, "{label}")
+    }
+}
+
+impl IpFormat {
+    /// Formats the address, or returns `None` for IPv4-only notations of an IPv6 address.
+    fn format(&self, address: IpAddr) -> Option<String> {
+        let ipv4 = match address {
+            IpAddr::V4(ipv4) => Some(ipv4),
+            IpAddr::V6(_) => None,
+        };
+        let ipv6 = match address {
+            IpAddr::V4(ipv4) => ipv4.to_ipv6_mapped(),
+            IpAddr::V6(ipv6) => ipv6,
+        };
+        let formatted = match self {
+            Self::Address => address.to_string(),
+            Self::Integer => address_to_u128(address).to_string(),
+            Self::Hexadecimal => match address {
+                IpAddr::V4(ipv4) => format!("0x{:08X}", u32::from(ipv4)),
+                IpAddr::V6(ipv6) => format!("0x{:032X}", u128::from(ipv6)),
+            },
+            Self::Binary => match address {
+                IpAddr::V4(ipv4) => join_octets(ipv4, |octet| format!("{octet:08b}")),
+                IpAddr::V6(ipv6) => ipv6
+                    .segments()
+                    .iter()
+                    .map(|segment| format!("{segment:016b}"))
+                    .collect::<Vec<_>>()
+                    .join(":"),
+            },
+            Self::DottedOctal => join_octets(ipv4?, |octet| format!("{octet:04o}")),
+            Self::DottedHexadecimal => join_octets(ipv4?, |octet| format!("0x{octet:02X}")),
+            Self::Ipv4Mapped => format!("::ffff:{}", ipv4?),
+            Self::ExpandedIpv6 => expanded_ipv6(&ipv6),
+            Self::PtrName => reverse_dns_zone(&IpCidr::new_host(address)),
+        };
+        Some(formatted)
+    }
+
+    fn parse(&self, input: &str) -> Result<IpAddr, String> {
+        let address = match self {
+            Self::Address | Self::PtrName => parse_any_notation(input),
+            Self::Integer => parse_integer(input, 10),
+            Self::Hexadecimal => parse_integer(
+                input
+                    .trim_start_matches("0x")
+                    .trim_start_matches("0X")
+                    .replace([' ', ':', '.', '_'], "")
+                    .as_str(),
+                16,
+            ),
+            Self::Binary => parse_integer(
+                input
+                    .trim_start_matches("0b")
+                    .trim_start_matches("0B")
+                    .replace([' ', ':', '.', '_'], "")
+                    .as_str(),
+                2,
+            ),
+            Self::DottedOctal | Self::DottedHexadecimal => parse_inet_aton(input).map(IpAddr::V4),
+            Self::Ipv4Mapped | Self::ExpandedIpv6 => Ipv6Addr::from_str(input).ok().map(IpAddr::V6),
+        };
+        address.ok_or_else(|| format!("\"{input}\" cannot be read as {self}."))
+    }
+}
+
+fn join_octets(address: Ipv4Addr, format_octet: impl Fn(u8) -> String) -> String {
+    address
+        .octets()
+        .into_iter()
+        .map(format_octet)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Parses an integer as an IPv4 address when it fits in 32 bits and as IPv6 otherwise. Hex
+/// and binary input with all digits written out keeps its width, so `::1` survives a round
+/// trip through the hexadecimal and binary fields.
+fn parse_integer(digits: &str, radix: u32) -> Option<IpAddr> {
+    let value = u128::from_str_radix(digits, radix).ok()?;
+    let ipv4_digits = match radix {
+        16 => 8,
+        2 => 32,
+        _ => usize::MAX,
+    };
+    let family = match value <= u128::from(u32::MAX) && digits.len() <= ipv4_digits {
+        true => cidr::Family::Ipv4,
+        false => cidr::Family::Ipv6,
+    };
+    Some(address_from_u128(value, &family))
+}
+
+/// Accepts standard IPv4/IPv6 notation, `inet_aton` style IPv4 (octal, hex, integer or
+/// shortened parts), hex with a `0x` prefix, binary with a `0b` prefix or dotted 8-bit groups,
+/// and `in-addr.arpa`/`ip6.arpa` names.
+fn parse_any_notation(input: &str) -> Option<IpAddr> {
+    if let Ok(address) = IpAddr::from_str(input) {
+        return Some(address);
+    }
+    let lowercase = input.trim_end_matches('.').to_lowercase();
+    if let Some(address) = parse_ptr_name(&lowercase) {
+        return Some(address);
+    }
+    // Binary goes first, since dotted 8-bit groups would also pass as octal parts.
+    let binary = lowercase
+        .strip_prefix("0b")
+        .unwrap_or(&lowercase)
+        .replace([' ', ':', '.', '_'], "");
+    let is_binary = binary.chars().all(|digit| digit == '0' || digit == '1');
+    if is_binary && (lowercase.starts_with("0b") || matches!(binary.len(), 32 | 128)) {
+        return parse_integer(&binary, 2);
+    }
+    if let Some(address) = parse_inet_aton(&lowercase) {
+        return Some(IpAddr::V4(address));
+    }
+    match lowercase.strip_prefix("0x") {
+        Some(hex) => parse_integer(&hex.replace([' ', ':', '_'], ""), 16),
+        None => parse_integer(&lowercase, 10),
+    }
+}
+
+/// Parses IPv4 the way `inet_aton` does: one to four parts, each decimal, octal with a
+/// leading `0` or hex with `0x`, where the last part fills all remaining bytes.
+fn parse_inet_aton(input: &str) -> Option<Ipv4Addr> {
+    let parts: Vec<u32> = input
+        .split('.')
+        .map(|part| {
+            let lowercase = part.to_lowercase();
+            match lowercase.strip_prefix("0x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None if lowercase.len() > 1 && lowercase.starts_with('0') => {
+                    u32::from_str_radix(&lowercase[1..], 8).ok()
+                }
+                None => lowercase.parse::<u32>().ok(),
+            }
+        })
+        .collect::<Option<_>>()?;
+    let (last, leading) = parts.split_last()?;
+    if leading.len() > 3 || leading.iter().any(|part| *part > 0xFF) {
+        return None;
+    }
+    let last_bits = 32 - 8 * leading.len() as u32;
+    if last_bits < 32 && *last >= 1 << last_bits {
+        return None;
+    }
+    let leading = leading
+        .iter()
+        .enumerate()
+        .fold(0u32, |address, (index, part)| {
+            address | part << (24 - 8 * index)
+        });
+    Some(Ipv4Addr::from(leading | last))
+}
+
+fn parse_ptr_name(input: &str) -> Option<IpAddr> {
+    if let Some(labels) = input.strip_suffix(".in-addr.arpa") {
+        let mut octets: Vec<u8> = labels
+            .split('.')
+            .map(|label| label.parse::<u8>().ok())
+            .collect::<Option<_>>()?;
+        octets.reverse();
+        let octets: [u8; 4] = octets.try_into().ok()?;
+        return Some(IpAddr::V4(Ipv4Addr::from(octets)));
+    }
+    let labels = input.strip_suffix(".ip6.arpa")?;
+    let nibbles: Vec<u128> = labels
+        .split('.')
+        .map(|label| {
+            u8::from_str_radix(label, 16)
+                .ok()
+                .filter(|_| label.len() == 1)
+        })
+        .map(|nibble| nibble.map(u128::from))
+        .collect::<Option<_>>()?;
+    if nibbles.len() != 32 {
+        return None;
+    }
+    let address = nibbles
+        .iter()
+        .rev()
+        .fold(0u128, |address, nibble| address << 4 | nibble);
+    Some(IpAddr::V6(Ipv6Addr::from(address)))
+}
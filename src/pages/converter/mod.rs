@@ -4,8 +4,11 @@ use dioxus_free_icons::Icon;
 use strum_macros::EnumIter;
 
 pub mod date_converter;
+pub mod float_inspector;
+pub mod ip_format_converter;
 pub mod json_yaml_converter;
 pub mod number_base_converter;
+pub mod programmer_calculator;
 
 use crate::pages::{CategoryEntry, Route, WidgetEntry, WidgetRoute};
 
@@ -19,8 +22,11 @@ pub static CATEGORY_ENTRY: CategoryEntry = CategoryEntry {
     },
 };
 use date_converter::DateConverter;
+use float_inspector::FloatInspector;
+use ip_format_converter::IpFormatConverter;
 use json_yaml_converter::JsonYamlConverter;
 use number_base_converter::NumberBaseConverter;
+use programmer_calculator::ProgrammerCalculator;
 
 #[derive(Clone, Debug, EnumIter, PartialEq, Routable)]
 pub enum ConverterRoute {
@@ -28,10 +34,16 @@ pub enum ConverterRoute {
     Index {},
     #[route("/date")]
     DateConverter {},
+    #[route("/float-inspector")]
+    FloatInspector {},
+    #[route("/ip-format")]
+    IpFormatConverter {},
     #[route("/json-yaml")]
     JsonYamlConverter {},
     #[route("/number-base")]
     NumberBaseConverter {},
+    #[route("/programmer-calculator")]
+    ProgrammerCalculator {},
 }
 
 fn Index() -> Element {
@@ -57,8 +69,11 @@ impl WidgetRoute for ConverterRoute {
     fn get_widget_entry(&self) -> Option<&'static WidgetEntry> {
         match self {
             Self::DateConverter { .. } => Some(&date_converter::WIDGET_ENTRY),
+            Self::FloatInspector { .. } => Some(&float_inspector::WIDGET_ENTRY),
+            Self::IpFormatConverter { .. } => Some(&ip_format_converter::WIDGET_ENTRY),
             Self::JsonYamlConverter { .. } => Some(&json_yaml_converter::WIDGET_ENTRY),
             Self::NumberBaseConverter { .. } => Some(&number_base_converter::WIDGET_ENTRY),
+            Self::ProgrammerCalculator { .. } => Some(&programmer_calculator::WIDGET_ENTRY),
             _ => None,
         }
     }
@@ -1,110 +1,525 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 use dioxus_free_icons::icons::bs_icons::Bs123;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
 use std::fmt;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
 
-use crate::components::inputs::{SwitchInput, TextInput};
+use crate::components::inputs::{NumberInput, SelectForm, SelectFormEnum, SwitchInput, TextInput};
 use crate::pages::{WidgetEntry, WidgetIcon};
-use crate::utils::{add_number_delimiters, sanitize_string};
+use crate::utils::add_number_delimiters;
 
 pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
     title: "Number Base Converter",
     short_title: "Number Base",
-    description: "Convert numbers between binary, octal, decimal, and hexadecimal",
+    description: "Convert integers and fractions of any size between bases 2 to 36 and 64",
     icon: move || ICON.icon(),
 };
 
 const ICON: WidgetIcon<Bs123> = WidgetIcon { icon: Bs123 };
 
+/// Digits for bases up to 36; letters are case-insensitive when parsing.
+const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Digits for base 64, in the order of the Base64 alphabet.
+const BASE64_DIGITS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Fractions that do not terminate in the target base are cut off after this many digits.
+const MAX_FRACTION_DIGITS: usize = 64;
+
 pub fn NumberBaseConverter() -> Element {
-    use_context_provider(|| Signal::new(ConverterValue(0)));
-    let mut format_number_state = use_context_provider(|| Signal::new(FormatNumberState(false)));
+    let value_context = use_context_provider(|| {
+        Signal::new(ConverterValue {
+            value: BigRational::zero(),
+            editing: None,
+            error: None,
+        })
+    });
+    let mut options = use_context_provider(|| {
+        Signal::new(ConverterOptions {
+            format_number: false,
+            custom_radix: 36,
+            negative_representation: NegativeRepresentation::default(),
+            bit_width: BitWidth::default(),
+            signed: true,
+        })
+    });
+    let error = value_context.read().error.clone();
+    let bits = options.read().bit_width.bits();
 
     rsx! {
-        div { class: "widget-grid",
-            SwitchInput {
-                label: "Format Numbers",
-                checked: format_number_state.read().0,
-                oninput: move |is_enabled| {
-                    format_number_state.write().0 = is_enabled;
-                },
+        div { class: "widget-grid number-base-converter",
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Format Numbers",
+                    checked: options.read().format_number,
+                    oninput: move |is_enabled| {
+                        options.write().format_number = is_enabled;
+                    },
+                }
+                if bits.is_some() {
+                    SwitchInput {
+                        label: "Signed",
+                        checked: options.read().signed,
+                        oninput: move |is_enabled| {
+                            options.write().signed = is_enabled;
+                            fit_to_width(value_context, &options.read());
+                        },
+                    }
+                }
+            }
+            div { class: "widget-params",
+                SelectForm::<BitWidth> {
+                    label: "Bit Width",
+                    oninput: move |bit_width| {
+                        options.write().bit_width = bit_width;
+                        fit_to_width(value_context, &options.read());
+                    },
+                    value: options.read().bit_width,
+                }
+                if bits.is_none() {
+                    SelectForm::<NegativeRepresentation> {
+                        label: "Negative Numbers",
+                        oninput: move |representation| {
+                            options.write().negative_representation = representation;
+                        },
+                        value: options.read().negative_representation,
+                    }
+                }
+                NumberInput::<u32> {
+                    label: "Custom Base (2-36 or 64)",
+                    value: options.read().custom_radix,
+                    onchange: move |radix: u32| {
+                        let current = options.read().custom_radix;
+                        // Bases 37 to 63 are skipped in both directions of the stepper.
+                        options.write().custom_radix = match radix {
+                            0..=2 => 2,
+                            3..=36 => radix,
+                            37..=63 if current == 64 => 36,
+                            _ => 64,
+                        };
+                    },
+                }
             }
             converter_input { base: NumberBase::Decimal }
             converter_input { base: NumberBase::Hexadecimal }
             converter_input { base: NumberBase::Octal }
             converter_input { base: NumberBase::Binary }
+            converter_input { base: NumberBase::Custom }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            if let Some(bits) = bits {
+                BitView { bits }
+            }
         }
     }
 }
 
+#[component]
+fn BitView(bits: u64) -> Element {
+    let mut value_context = use_context::<Signal<ConverterValue>>();
+    let options = use_context::<Signal<ConverterOptions>>();
+    let signed = options.read().signed;
+
+    let pattern = bit_pattern(&value_context.read().value.to_integer(), bits);
+    let bytes_be = pattern_bytes(&pattern, bits);
+    let bytes_le: Vec<u8> = bytes_be.iter().rev().copied().collect();
+    let unsigned_value = pattern.to_string();
+    let signed_value = wrap_to_width(&pattern, bits, true).to_string();
+
+    let mut set_pattern = move |pattern: BigInt| {
+        value_context.with_mut(|value| {
+            value.value = BigRational::from_integer(wrap_to_width(&pattern, bits, signed));
+            value.editing = None;
+            value.error = None;
+        });
+    };
+
+    rsx! {
+        div { class: "widget-params",
+            TextInput { label: "Unsigned Value", value: "{unsigned_value}", readonly: true }
+            TextInput { label: "Signed Value", value: "{signed_value}", readonly: true }
+        }
+        div { class: "widget-params",
+            TextInput {
+                label: "Big-Endian Bytes",
+                value: format_bytes(&bytes_be),
+                readonly: true,
+            }
+            TextInput {
+                label: "Little-Endian Bytes",
+                value: format_bytes(&bytes_le),
+                readonly: true,
+            }
+        }
+        div { class: "widget-buttons",
+            button {
+                class: "btn btn-info",
+                onclick: move |_| set_pattern(BigInt::from_bytes_be(Sign::Plus, &bytes_le)),
+                "Swap Byte Order"
+            }
+        }
+        div { class: "bit-grid",
+            for byte in (0..bits / 8).rev() {
+                div { key: "{byte}", class: "bit-byte",
+                    div { class: "bit-byte-bits",
+                        for bit in (byte * 8..byte * 8 + 8).rev() {
+                            button {
+                                key: "{bit}",
+                                class: if pattern.bit(bit) { "bit set" } else { "bit" },
+                                title: "Bit {bit}",
+                                onclick: {
+                                    let pattern = pattern.clone();
+                                    move |_| set_pattern(pattern.clone() ^ (BigInt::one() << bit))
+                                },
+                                if pattern.bit(bit) {
+                                    "1"
+                                } else {
+                                    "0"
+                                }
+                            }
+                        }
+                    }
+                    span { class: "bit-byte-label", "{byte * 8 + 7}–{byte * 8}" }
+                }
+            }
+        }
+    }
+}
+
+/// Wraps the current value into the selected bit width, as storing it in a register would.
+fn fit_to_width(mut value_context: Signal<ConverterValue>, options: &ConverterOptions) {
+    let Some(bits) = options.bit_width.bits() else {
+        return;
+    };
+    value_context.with_mut(|value| {
+        let integer = value.value.trunc().to_integer();
+        value.value = BigRational::from_integer(wrap_to_width(&integer, bits, options.signed));
+        value.editing = None;
+        value.error = None;
+    });
+}
+
+/// The low `bits` bits of the number's two's complement representation.
+fn bit_pattern(number: &BigInt, bits: u64) -> BigInt {
+    let modulus = BigInt::one() << bits;
+    ((number % &modulus) + &modulus) % &modulus
+}
+
+pub(crate) fn wrap_to_width(number: &BigInt, bits: u64, signed: bool) -> BigInt {
+    let pattern = bit_pattern(number, bits);
+    match signed && pattern.bit(bits - 1) {
+        true => pattern - (BigInt::one() << bits),
+        false => pattern,
+    }
+}
+
+fn pattern_bytes(pattern: &BigInt, bits: u64) -> Vec<u8> {
+    let (_, bytes) = pattern.to_bytes_be();
+    let mut padded = vec![0; (bits / 8) as usize - bytes.len().min((bits / 8) as usize)];
+    padded.extend(bytes);
+    padded
+}
+
+fn format_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[component]
 fn converter_input(base: NumberBase) -> Element {
     let mut value_context = use_context::<Signal<ConverterValue>>();
-    let format_number_state = use_context::<Signal<FormatNumberState>>();
+    let options = use_context::<Signal<ConverterOptions>>();
+    let radix = base.radix(options.read().custom_radix);
+
+    // The field being edited keeps its raw text so that partial input is not reformatted.
+    let value = value_context.with(|value| match &value.editing {
+        Some((editing_base, input)) if *editing_base == base => input.clone(),
+        _ => format_number(&value.value, base, &options.read()),
+    });
+    let label = match base {
+        NumberBase::Custom => format!("Base {radix}"),
+        _ => base.to_string(),
+    };
+    let error_label = label.clone();
 
     rsx! {
         TextInput {
-            label: "{base}",
-            value: "{format_number(value_context.read().0, base, format_number_state.read().0)}",
+            label: "{label}",
+            value: "{value}",
             oninput: move |event: Event<FormData>| {
-                let event_value = sanitize_string(event.value());
-                value_context.write().0 = match base {
-                    NumberBase::Binary => i64::from_str_radix(&event_value, 2),
-                    NumberBase::Octal => i64::from_str_radix(&event_value, 8),
-                    NumberBase::Decimal => event_value.parse::<i64>(),
-                    NumberBase::Hexadecimal => i64::from_str_radix(&event_value, 16),
-                }
-                    .unwrap_or(0);
+                let input = event.value();
+                let options = options.read().clone();
+                value_context
+                    .with_mut(|value| {
+                        match parse_input(&input, radix, &options) {
+                            Ok(number) => {
+                                value.value = number;
+                                value.error = None;
+                            }
+                            Err(err) => {
+                                value.error = Some(format!("Invalid {error_label} number: {err}"));
+                            }
+                        }
+                        value.editing = Some((base, input));
+                    });
+            },
+            onchange: move |_| {
+                value_context
+                    .with_mut(|value| {
+                        value.editing = None;
+                        value.error = None;
+                    });
             },
         }
     }
 }
 
-fn format_number(number: i64, base: NumberBase, format_number: bool) -> String {
-    match base {
-        NumberBase::Binary => {
-            let number_binary = format!("{:b}", number);
-            match format_number {
-                true => add_number_delimiters(number_binary, ' ', 4),
-                false => number_binary,
-            }
-        }
-        NumberBase::Octal => {
-            let number_octal = format!("{:o}", number);
-            match format_number {
-                true => add_number_delimiters(number_octal, ' ', 3),
-                false => number_octal,
-            }
-        }
-        NumberBase::Decimal => {
-            let number_decimal = format!("{}", number);
-            match format_number {
-                true => add_number_delimiters(number_decimal, ',', 3),
-                false => number_decimal,
-            }
-        }
-        NumberBase::Hexadecimal => {
-            let number_hexadecimal = format!("{:X}", number);
-            match format_number {
-                true => add_number_delimiters(number_hexadecimal, ' ', 4),
-                false => number_hexadecimal,
-            }
+pub(crate) fn format_number(
+    number: &BigRational,
+    base: NumberBase,
+    options: &ConverterOptions,
+) -> String {
+    let radix = base.radix(options.custom_radix);
+    let twos_complement_width = match (options.bit_width.bits(), options.negative_representation) {
+        (Some(bits), _) => Some(bits),
+        (None, NegativeRepresentation::TwosComplement) => Some(narrowest_width(number.numer())),
+        (None, NegativeRepresentation::MinusSign) => None,
+    };
+    let (sign, integer, fraction) = match twos_complement_width {
+        Some(width) if number.is_integer() && radix.is_power_of_two() => (
+            String::new(),
+            twos_complement_digits(number.numer(), radix, width),
+            None,
+        ),
+        _ => {
+            let magnitude = number.abs();
+            let integer = digits_to_string(
+                &magnitude
+                    .trunc()
+                    .to_integer()
+                    .magnitude()
+                    .to_radix_be(radix),
+                radix,
+            );
+            let sign = match number.is_negative() {
+                true => "-".to_string(),
+                false => String::new(),
+            };
+            (sign, integer, fraction_digits(&magnitude.fract(), radix))
         }
+    };
+    let integer = match options.format_number {
+        true => match base {
+            NumberBase::Binary => add_number_delimiters(integer, ' ', 4),
+            NumberBase::Octal => add_number_delimiters(integer, ' ', 3),
+            NumberBase::Decimal => add_number_delimiters(integer, ',', 3),
+            NumberBase::Hexadecimal | NumberBase::Custom => add_number_delimiters(integer, ' ', 4),
+        },
+        false => integer,
+    };
+    match fraction {
+        Some(fraction) => format!("{sign}{integer}.{fraction}"),
+        None => format!("{sign}{integer}"),
+    }
+}
+
+/// Writes the digits of a fraction, ending in `…` when it does not terminate.
+fn fraction_digits(fraction: &BigRational, radix: u32) -> Option<String> {
+    if fraction.is_zero() {
+        return None;
+    }
+    let radix_ratio = BigRational::from_integer(BigInt::from(radix));
+    let mut remainder = fraction.clone();
+    let mut digits = Vec::new();
+    while !remainder.is_zero() && digits.len() < MAX_FRACTION_DIGITS {
+        remainder *= &radix_ratio;
+        let digit = remainder.trunc();
+        digits.push(digit.to_integer().to_u8().unwrap_or_default());
+        remainder -= digit;
+    }
+    let mut fraction = digits_to_string(&digits, radix);
+    if !remainder.is_zero() {
+        fraction.push('…');
+    }
+    Some(fraction)
+}
+
+/// The narrowest 8, 16, 32, 64, ... bit two's complement integer that holds the number.
+fn narrowest_width(number: &BigInt) -> u64 {
+    // -2^(w-1) still fits in w bits, so negative numbers need one bit less than their magnitude.
+    let value_bits = match number.sign() {
+        Sign::Minus => (-number - 1u8).bits(),
+        _ => number.bits(),
+    };
+    let mut width = 8;
+    while value_bits + 1 > width {
+        width *= 2;
+    }
+    width
+}
+
+/// Writes an integer as a two's complement bit pattern, padded to the full width so the sign
+/// bit is visible.
+fn twos_complement_digits(number: &BigInt, radix: u32, width: u64) -> String {
+    let pattern = bit_pattern(number, width);
+    let digits = digits_to_string(&pattern.magnitude().to_radix_be(radix), radix);
+    let width_digits = (width as usize).div_ceil(radix.trailing_zeros() as usize);
+    format!("{digits:0>width_digits$}")
+}
+
+fn digits_to_string(digits: &[u8], radix: u32) -> String {
+    let alphabet = match radix {
+        64 => BASE64_DIGITS,
+        _ => DIGITS,
+    };
+    digits
+        .iter()
+        .map(|digit| char::from(alphabet[usize::from(*digit)]))
+        .collect()
+}
+
+/// Parses a field, wrapping the value into the bit width when one is selected. A fixed width
+/// reads digits as a plain bit pattern, so `FF` in 8 signed bits is -1.
+fn parse_input(input: &str, radix: u32, options: &ConverterOptions) -> Result<BigRational, String> {
+    let Some(bits) = options.bit_width.bits() else {
+        return parse_number(input, radix, options.negative_representation);
+    };
+    let number = parse_number(input, radix, NegativeRepresentation::MinusSign)?;
+    if !number.is_integer() {
+        return Err(format!("a {bits}-bit integer cannot hold a fraction"));
+    }
+    Ok(BigRational::from_integer(wrap_to_width(
+        number.numer(),
+        bits,
+        options.signed,
+    )))
+}
+
+/// Parses a number with an optional sign, `0x`/`0o`/`0b` prefix and fractional part. Spaces,
+/// underscores and (outside base 64) commas are ignored so that formatted output parses back.
+fn parse_number(
+    input: &str,
+    radix: u32,
+    representation: NegativeRepresentation,
+) -> Result<BigRational, String> {
+    let cleaned: String = input
+        .chars()
+        .filter(|character| {
+            !character.is_whitespace()
+                && !matches!(character, '_' | '…')
+                && (*character != ',' || radix == 64)
+        })
+        .collect();
+    let (is_negative, unsigned) = match cleaned.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        // `+` is a digit in base 64.
+        None if radix == 64 => (false, cleaned.as_str()),
+        None => (false, cleaned.strip_prefix('+').unwrap_or(&cleaned)),
+    };
+    let prefix = match radix {
+        2 => Some("0b"),
+        8 => Some("0o"),
+        16 => Some("0x"),
+        _ => None,
+    };
+    let unsigned = prefix
+        .and_then(|prefix| {
+            unsigned
+                .strip_prefix(prefix)
+                .or_else(|| unsigned.strip_prefix(&prefix.to_uppercase()))
+        })
+        .unwrap_or(unsigned);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err("enter at least one digit".to_string());
+    }
+    let digits = integer
+        .chars()
+        .chain(fraction.chars())
+        .map(|character| digit_value(character, radix))
+        .collect::<Result<Vec<u8>, String>>()?;
+    let magnitude = BigUint::from_radix_be(&digits, radix).unwrap_or_default();
+    let mut number = BigRational::new(
+        BigInt::from(magnitude),
+        BigInt::from(radix).pow(fraction.len() as u32),
+    );
+
+    if representation == NegativeRepresentation::TwosComplement
+        && radix.is_power_of_two()
+        && fraction.is_empty()
+        && !is_negative
+    {
+        number = BigRational::from_integer(from_twos_complement(
+            number.to_integer(),
+            integer.len(),
+            radix,
+        ));
+    }
+    Ok(match is_negative {
+        true => -number,
+        false => number,
+    })
+}
+
+/// Reads a bit pattern as the narrowest 8, 16, 32, 64, ... bit two's complement integer whose
+/// digit count matches the input, so that padded output parses back to the same value.
+fn from_twos_complement(pattern: BigInt, digit_count: usize, radix: u32) -> BigInt {
+    let bits_per_digit = radix.trailing_zeros() as usize;
+    let mut width: usize = 8;
+    while width.div_ceil(bits_per_digit) < digit_count {
+        width *= 2;
+    }
+    match pattern.bit(width as u64 - 1) {
+        true => pattern - (BigInt::one() << width),
+        false => pattern,
     }
 }
 
+fn digit_value(character: char, radix: u32) -> Result<u8, String> {
+    let value = match radix {
+        64 => BASE64_DIGITS
+            .iter()
+            .position(|digit| char::from(*digit) == character),
+        _ => character
+            .to_digit(36)
+            .map(|value| value as usize)
+            .filter(|value| *value < radix as usize),
+    };
+    value
+        .map(|value| value as u8)
+        .ok_or_else(|| format!("'{character}' is not a base {radix} digit"))
+}
+
 #[derive(Clone)]
-struct ConverterValue(i64);
+struct ConverterValue {
+    value: BigRational,
+    editing: Option<(NumberBase, String)>,
+    error: Option<String>,
+}
 
 #[derive(Clone)]
-struct FormatNumberState(bool);
+pub(crate) struct ConverterOptions {
+    pub(crate) format_number: bool,
+    pub(crate) custom_radix: u32,
+    pub(crate) negative_representation: NegativeRepresentation,
+    pub(crate) bit_width: BitWidth,
+    pub(crate) signed: bool,
+}
 
 #[derive(PartialEq, Debug, Clone, Copy)]
-enum NumberBase {
+pub(crate) enum NumberBase {
     Binary,
     Octal,
     Decimal,
     Hexadecimal,
+    Custom,
 }
 
 impl fmt::Display for NumberBase {
@@ -112,3 +527,74 @@ impl fmt::Display for NumberBase {
         write!(f, "{:?}", self)
     }
 }
+
+impl NumberBase {
+    fn radix(&self, custom_radix: u32) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+            Self::Custom => custom_radix,
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+pub(crate) enum NegativeRepresentation {
+    #[default]
+    #[strum(to_string = "Minus sign")]
+    MinusSign,
+    #[strum(to_string = "Two's complement (power-of-two bases)")]
+    TwosComplement,
+}
+
+impl SelectFormEnum for NegativeRepresentation {}
+
+impl From<NegativeRepresentation> for String {
+    fn from(representation: NegativeRepresentation) -> Self {
+        representation.to_string()
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+pub(crate) enum BitWidth {
+    #[default]
+    #[strum(to_string = "Unlimited")]
+    Unlimited,
+    #[strum(to_string = "8-bit")]
+    Bits8,
+    #[strum(to_string = "16-bit")]
+    Bits16,
+    #[strum(to_string = "32-bit")]
+    Bits32,
+    #[strum(to_string = "64-bit")]
+    Bits64,
+    #[strum(to_string = "128-bit")]
+    Bits128,
+}
+
+impl BitWidth {
+    pub(crate) fn bits(&self) -> Option<u64> {
+        match self {
+            Self::Unlimited => None,
+            Self::Bits8 => Some(8),
+            Self::Bits16 => Some(16),
+            Self::Bits32 => Some(32),
+            Self::Bits64 => Some(64),
+            Self::Bits128 => Some(128),
+        }
+    }
+}
+
+impl SelectFormEnum for BitWidth {}
+
+impl From<BitWidth> for String {
+    fn from(bit_width: BitWidth) -> Self {
+        bit_width.to_string()
+    }
+}
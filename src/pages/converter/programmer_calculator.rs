@@ -0,0 +1,408 @@
+#![allow(non_snake_case)]
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaCalculator;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Num, One, Signed, ToPrimitive, Zero};
+
+use crate::components::inputs::{SelectForm, SwitchInput, TextAreaForm, TextInput};
+use crate::pages::converter::number_base_converter::{
+    format_number, wrap_to_width, BitWidth, ConverterOptions, NegativeRepresentation, NumberBase,
+};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Programmer's Calculator",
+    short_title: "Programmer Calc",
+    description: "Evaluate bitwise integer expressions with mixed bases and variables",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaCalculator> = WidgetIcon { icon: FaCalculator };
+
+/// Shifts, products and powers whose result would exceed this many bits are rejected instead
+/// of allocating huge numbers.
+const MAX_RESULT_BITS: u64 = 1 << 20;
+
+pub fn ProgrammerCalculator() -> Element {
+    let mut expressions_input = use_signal(|| {
+        "mask = 0xff & 0b1010 << 3\nflags = mask | 1 << 12\n~flags & 0xffff\n2 ** 100 % 1_000_007"
+            .to_string()
+    });
+    let mut options = use_signal(|| ConverterOptions {
+        format_number: true,
+        custom_radix: 36,
+        negative_representation: NegativeRepresentation::default(),
+        bit_width: BitWidth::default(),
+        signed: true,
+    });
+
+    let (results, error) = evaluate_lines(&expressions_input.read(), &options.read());
+    let bits = options.read().bit_width.bits();
+
+    rsx! {
+        div { class: "widget",
+            TextAreaForm {
+                label: "Expressions (one per line, name = expression assigns a variable)",
+                value: "{expressions_input}",
+                oninput: move |event: Event<FormData>| expressions_input.set(event.value()),
+            }
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Format Numbers",
+                    checked: options.read().format_number,
+                    oninput: move |is_enabled| {
+                        options.write().format_number = is_enabled;
+                    },
+                }
+                if bits.is_some() {
+                    SwitchInput {
+                        label: "Signed",
+                        checked: options.read().signed,
+                        oninput: move |is_enabled| {
+                            options.write().signed = is_enabled;
+                        },
+                    }
+                }
+            }
+            div { class: "widget-params",
+                SelectForm::<BitWidth> {
+                    label: "Bit Width",
+                    oninput: move |bit_width| {
+                        options.write().bit_width = bit_width;
+                    },
+                    value: options.read().bit_width,
+                }
+                if bits.is_none() {
+                    SelectForm::<NegativeRepresentation> {
+                        label: "Negative Numbers",
+                        oninput: move |representation| {
+                            options.write().negative_representation = representation;
+                        },
+                        value: options.read().negative_representation,
+                    }
+                }
+            }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            if let Some(last) = results.last() {
+                for base in [
+                    NumberBase::Decimal,
+                    NumberBase::Hexadecimal,
+                    NumberBase::Octal,
+                    NumberBase::Binary,
+                ]
+                {
+                    TextInput {
+                        label: "{base}",
+                        value: format_number(&BigRational::from_integer(last.value.clone()), base, &options.read()),
+                        readonly: true,
+                    }
+                }
+                div { class: "overflow-x-auto",
+                    table { class: "table table-sm font-mono",
+                        thead {
+                            tr {
+                                th { "Expression" }
+                                th { "Decimal" }
+                                th { "Hexadecimal" }
+                            }
+                        }
+                        tbody {
+                            for (index , result) in results.iter().enumerate() {
+                                tr { key: "{index}",
+                                    td { "{result.expression}" }
+                                    td {
+                                        {
+                                            format_number(
+                                                &BigRational::from_integer(result.value.clone()),
+                                                NumberBase::Decimal,
+                                                &options.read(),
+                                            )
+                                        }
+                                    }
+                                    td {
+                                        {
+                                            format_number(
+                                                &BigRational::from_integer(result.value.clone()),
+                                                NumberBase::Hexadecimal,
+                                                &options.read(),
+                                            )
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct LineResult {
+    expression: String,
+    value: BigInt,
+}
+
+/// Evaluates every non-empty line in order, stopping at the first error. Variables assigned
+/// on earlier lines and `ans`, the previous result, are visible to later lines. With a bit
+/// width selected, every line's result is wrapped to that width.
+fn evaluate_lines(input: &str, options: &ConverterOptions) -> (Vec<LineResult>, Option<String>) {
+    let mut variables: HashMap<String, BigInt> = HashMap::new();
+    let mut results = Vec::new();
+    for (line_number, line) in input.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let evaluated = tokenize(line).and_then(|tokens| {
+            let (name, expression) = match tokens.as_slice() {
+                [Token::Identifier(name), Token::Operator("="), expression @ ..] => {
+                    (Some(name.clone()), expression)
+                }
+                _ => (None, tokens.as_slice()),
+            };
+            let mut parser = Parser {
+                tokens: expression,
+                position: 0,
+                variables: &variables,
+            };
+            parser.parse().map(|value| (name, value))
+        });
+        let (name, value) = match evaluated {
+            Ok((name, value)) => match options.bit_width.bits() {
+                Some(bits) => (name, wrap_to_width(&value, bits, options.signed)),
+                None => (name, value),
+            },
+            Err(err) => return (results, Some(format!("Line {}: {err}", line_number + 1))),
+        };
+        if let Some(name) = name {
+            variables.insert(name, value.clone());
+        }
+        variables.insert("ans".to_string(), value.clone());
+        results.push(LineResult {
+            expression: line.to_string(),
+            value,
+        });
+    }
+    (results, None)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(BigInt),
+    Identifier(String),
+    Operator(&'static str),
+}
+
+/// Operators, longest first so that `**`, `<<` and `>>` win over their one-character prefixes.
+const OPERATORS: &[&str] = &[
+    "**", "<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^", "~", "(", ")", "=",
+];
+
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = line;
+    while let Some(character) = rest.chars().next() {
+        if character.is_whitespace() {
+            rest = &rest[character.len_utf8()..];
+        } else if character.is_ascii_alphanumeric() || character == '_' {
+            let end = rest
+                .find(|character: char| !character.is_ascii_alphanumeric() && character != '_')
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            tokens.push(match character.is_ascii_digit() {
+                true => Token::Number(parse_literal(word)?),
+                false => Token::Identifier(word.to_string()),
+            });
+            rest = &rest[end..];
+        } else if let Some(operator) = OPERATORS
+            .iter()
+            .find(|operator| rest.starts_with(**operator))
+        {
+            tokens.push(Token::Operator(operator));
+            rest = &rest[operator.len()..];
+        } else {
+            return Err(format!("unexpected character '{character}'"));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Reads a decimal literal or one prefixed with `0x`, `0o` or `0b`; `_` separates digits.
+fn parse_literal(word: &str) -> Result<BigInt, String> {
+    let lowercase = word.to_lowercase().replace('_', "");
+    let (digits, radix) = match lowercase.get(..2) {
+        Some("0x") => (&lowercase[2..], 16),
+        Some("0o") => (&lowercase[2..], 8),
+        Some("0b") => (&lowercase[2..], 2),
+        _ => (lowercase.as_str(), 10),
+    };
+    BigInt::from_str_radix(digits, radix).map_err(|_| format!("'{word}' is not a valid number"))
+}
+
+/// A recursive descent parser with Python's operator precedence, from lowest to highest:
+/// `|`, `^`, `&`, shifts, `+ -`, `* / %`, unary `- + ~`, and the right-associative `**`.
+/// Division and remainder truncate toward zero as in C.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    variables: &'a HashMap<String, BigInt>,
+}
+
+impl Parser<'_> {
+    fn parse(&mut self) -> Result<BigInt, String> {
+        if self.tokens.is_empty() {
+            return Err("expected an expression".to_string());
+        }
+        let value = self.binary(0)?;
+        match self.tokens.get(self.position) {
+            None => Ok(value),
+            Some(token) => Err(format!("unexpected {}", describe_token(token))),
+        }
+    }
+
+    fn next_operator(&mut self, operators: &[&str]) -> Option<&'static str> {
+        match self.tokens.get(self.position) {
+            Some(Token::Operator(operator)) if operators.contains(operator) => {
+                self.position += 1;
+                Some(operator)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses the left-associative binary operators of `PRECEDENCE[level]` and above.
+    fn binary(&mut self, level: usize) -> Result<BigInt, String> {
+        const PRECEDENCE: &[&[&str]] = &[
+            &["|"],
+            &["^"],
+            &["&"],
+            &["<<", ">>"],
+            &["+", "-"],
+            &["*", "/", "%"],
+        ];
+        let Some(operators) = PRECEDENCE.get(level) else {
+            return self.unary();
+        };
+        let mut value = self.binary(level + 1)?;
+        while let Some(operator) = self.next_operator(operators) {
+            let right = self.binary(level + 1)?;
+            value = apply_binary(operator, value, right)?;
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<BigInt, String> {
+        match self.next_operator(&["-", "+", "~"]) {
+            Some("-") => Ok(-self.unary()?),
+            Some("~") => Ok(!self.unary()?),
+            Some(_) => self.unary(),
+            None => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<BigInt, String> {
+        let base = self.primary()?;
+        match self.next_operator(&["**"]) {
+            // The exponent may carry its own sign, as in `2 ** -1`.
+            Some(_) => apply_binary("**", base, self.unary()?),
+            None => Ok(base),
+        }
+    }
+
+    fn primary(&mut self) -> Result<BigInt, String> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        match token {
+            Some(Token::Number(value)) => Ok(value.clone()),
+            Some(Token::Identifier(name)) => self
+                .variables
+                .get(name)
+                .cloned()
+                .ok_or_else(|| format!("unknown variable '{name}'")),
+            Some(Token::Operator("(")) => {
+                let value = self.binary(0)?;
+                match self.next_operator(&[")"]) {
+                    Some(_) => Ok(value),
+                    None => Err("expected ')'".to_string()),
+                }
+            }
+            Some(token) => Err(format!("unexpected {}", describe_token(token))),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+fn describe_token(token: &Token) -> String {
+    match token {
+        Token::Number(value) => format!("number {value}"),
+        Token::Identifier(name) => format!("name '{name}'"),
+        Token::Operator(operator) => format!("'{operator}'"),
+    }
+}
+
+fn apply_binary(operator: &str, left: BigInt, right: BigInt) -> Result<BigInt, String> {
+    let value = match operator {
+        "|" => left | right,
+        "^" => left ^ right,
+        "&" => left & right,
+        "+" => left + right,
+        "-" => left - right,
+        "*" => {
+            if left.bits() + right.bits() > MAX_RESULT_BITS {
+                return Err(format!("the result would exceed {MAX_RESULT_BITS} bits"));
+            }
+            left * right
+        }
+        "/" | "%" if right.is_zero() => return Err("division by zero".to_string()),
+        "/" => left / right,
+        "%" => left % right,
+        "<<" | ">>" => {
+            let amount = shift_amount(&right)?;
+            if operator == ">>" {
+                // Arithmetic shift: negative numbers round toward negative infinity.
+                return Ok(left >> amount);
+            }
+            if left.bits() + amount > MAX_RESULT_BITS {
+                return Err(format!("the result would exceed {MAX_RESULT_BITS} bits"));
+            }
+            left << amount
+        }
+        "**" => {
+            if right.is_negative() {
+                return Err("negative exponents have no integer result".to_string());
+            }
+            if left.abs() <= BigInt::one() {
+                // 0, 1 and -1 stay small for any exponent.
+                return Ok(match (left.is_zero(), right.is_zero()) {
+                    (_, true) => BigInt::one(),
+                    (true, false) => BigInt::zero(),
+                    _ if left.is_negative() && right.bit(0) => -BigInt::one(),
+                    _ => BigInt::one(),
+                });
+            }
+            let exponent = right
+                .to_u32()
+                .filter(|exponent| left.bits() * u64::from(*exponent) <= MAX_RESULT_BITS)
+                .ok_or_else(|| format!("the result would exceed {MAX_RESULT_BITS} bits"))?;
+            left.pow(exponent)
+        }
+        _ => unreachable!("the parser only produces known operators"),
+    };
+    Ok(value)
+}
+
+fn shift_amount(amount: &BigInt) -> Result<u64, String> {
+    if amount.is_negative() {
+        return Err("shift amounts cannot be negative".to_string());
+    }
+    amount
+        .to_u64()
+        .filter(|amount| *amount <= MAX_RESULT_BITS)
+        .ok_or_else(|| format!("shift amounts are limited to {MAX_RESULT_BITS} bits"))
+}
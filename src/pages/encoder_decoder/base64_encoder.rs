@@ -1,11 +1,18 @@
 #![allow(non_snake_case)]
-use base64ct::{Base64, Encoding};
+use base64ct::{
+    Base64, Base64Bcrypt, Base64ShaCrypt, Base64Unpadded, Base64Url, Base64UrlUnpadded, Encoding,
+};
 use dioxus::prelude::*;
 use dioxus_free_icons::icons::fa_solid_icons::FaHashtag;
-use std::fmt;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
 
-use crate::components::inputs::TextAreaForm;
+use crate::components::decoded_bytes::DecodedBytesView;
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{
+    FileInput, LoadedFile, SelectForm, SelectFormEnum, SwitchInput, TextInput,
+};
 use crate::pages::{WidgetEntry, WidgetIcon};
+use crate::utils::detect_file_signature;
 
 pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
     title: "Base64 Encoder / Decoder",
@@ -17,79 +24,288 @@ pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
 const ICON: WidgetIcon<FaHashtag> = WidgetIcon { icon: FaHashtag };
 
 pub fn Base64Encoder() -> Element {
-    use_context_provider(|| {
+    let mut value_context = use_context_provider(|| {
         Signal::new(EncoderValue {
-            encoded_value: String::new(),
-            decoded_value: String::new(),
+            text: EncoderText::default(),
+            variant: Base64Variant::default(),
+            detected_variant: None,
+            data_uri: false,
         })
     });
-    rsx! {
-        div { class: "widget",
-            encoder_input { direction: Direction::Encode }
-            encoder_input { direction: Direction::Decode }
-        }
-    }
-}
-
-#[allow(unused_assignments, unused_variables)]
-#[component]
-fn encoder_input(direction: Direction) -> Element {
-    let mut value_context = use_context::<Signal<EncoderValue>>();
-
-    let current_value = value_context.with(|value| match direction {
-        Direction::Encode => value.decoded_value.clone(),
-        Direction::Decode => value.encoded_value.clone(),
-    });
+    let variant = value_context.read().variant;
+    let detected_variant = value_context.read().detected_variant.clone();
+    let is_binary = value_context.read().text.is_binary();
 
-    const NOT_STRING: &str = "Not String";
     rsx! {
-        TextAreaForm {
-            label: match direction {
-                Direction::Encode => "Text",
-                Direction::Decode => "Encoded",
-            },
-            value: "{current_value}",
-            oninput: move |event: Event<FormData>| {
-                let input_value = event.value();
-                match direction {
-                    Direction::Encode => {
+        div { class: "widget base64-encoder",
+            div { class: "widget-params",
+                SelectForm::<Base64Variant> {
+                    label: "Variant",
+                    oninput: move |variant: Base64Variant| {
                         value_context
-                            .set(EncoderValue {
-                                encoded_value: Base64::encode_string(input_value.as_bytes()),
-                                decoded_value: input_value,
+                            .with_mut(|value| {
+                                value.variant = variant;
+                                value.refresh();
                             });
+                    },
+                    value: variant,
+                }
+                if variant == Base64Variant::Auto {
+                    TextInput {
+                        label: "Detected Variant",
+                        value: detected_variant.unwrap_or_default(),
+                        readonly: true,
                     }
-                    Direction::Decode => {
-                        let decode_val = match Base64::decode_vec(input_value.as_str()) {
-                            Ok(val) => String::from_utf8(val).unwrap_or(NOT_STRING.to_string()),
-                            Err(_) => NOT_STRING.to_string(),
-                        };
+                }
+            }
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Encode as data: URI",
+                    checked: value_context.read().data_uri,
+                    oninput: move |is_enabled| {
                         value_context
-                            .set(EncoderValue {
-                                encoded_value: input_value,
-                                decoded_value: decode_val,
+                            .with_mut(|value| {
+                                value.data_uri = is_enabled;
+                                value.refresh();
                             });
-                    }
-                };
-            },
+                    },
+                }
+            }
+            FileInput {
+                label: "Encode File",
+                onload: move |file: LoadedFile| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(file.bytes);
+                            value.refresh_encoded();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary,
+                encoded_label: "Encoded",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.refresh_encoded();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary,
+                encoded_label: "Encoded",
+                oninput: move |input: String| value_context.with_mut(|value| value.decode(input)),
+            }
+            DecodedBytesView { bytes: value_context.read().text.decoded_bytes.clone() }
         }
     }
 }
 
 #[derive(Clone)]
 struct EncoderValue {
-    encoded_value: String,
-    decoded_value: String,
+    text: EncoderText,
+    variant: Base64Variant,
+    /// Description of the variant that matched in auto-detect mode.
+    detected_variant: Option<String>,
+    data_uri: bool,
 }
 
-#[derive(PartialEq, Debug, Clone)]
-enum Direction {
-    Encode,
-    Decode,
+const NOT_STRING: &str = "Not String";
+
+impl EncoderValue {
+    fn refresh_encoded(&mut self) {
+        self.text.encoded_value = self.encode();
+        self.detected_variant = None;
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn decode(&mut self, input: String) {
+        match self.variant.decode(strip_data_uri(&input)) {
+            Ok((decoded, detected_variant)) => {
+                self.text.set_decoded_bytes(decoded);
+                self.detected_variant = Some(detected_variant);
+            }
+            Err(_) => {
+                self.text.decoded_value = NOT_STRING.to_string();
+                self.text.decoded_bytes.clear();
+                self.detected_variant = None;
+            }
+        };
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Re-encodes the text or re-decodes the encoded input, whichever was typed last, after
+    /// the variant or data URI option changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.refresh_encoded(),
+            Direction::Decode => self.decode(self.text.encoded_value.clone()),
+        }
+    }
+
+    fn encode(&self) -> String {
+        match self.data_uri {
+            true => {
+                let mime_type = match detect_file_signature(&self.text.decoded_bytes) {
+                    Some(signature) => signature.mime_type,
+                    None if self.text.is_binary() => "application/octet-stream",
+                    None => "text/plain;charset=utf-8",
+                };
+                format!(
+                    "data:{mime_type};base64,{}",
+                    Base64::encode_string(&self.text.decoded_bytes)
+                )
+            }
+            false => self.variant.encode(&self.text.decoded_bytes),
+        }
+    }
 }
 
-impl fmt::Display for Direction {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+/// Returns the Base64 payload of a `data:` URI, or the input if it is not one.
+fn strip_data_uri(input: &str) -> &str {
+    match input.trim_start().strip_prefix("data:") {
+        Some(uri) => uri
+            .split_once(";base64,")
+            .map_or(input, |(_, payload)| payload),
+        None => input,
     }
 }
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum Base64Variant {
+    #[default]
+    Standard,
+    #[strum(to_string = "Standard (unpadded)")]
+    StandardUnpadded,
+    #[strum(to_string = "URL-safe")]
+    UrlSafe,
+    #[strum(to_string = "URL-safe (unpadded)")]
+    UrlSafeUnpadded,
+    #[strum(to_string = "MIME (76 columns)")]
+    Mime,
+    #[strum(to_string = "PEM (64 columns)")]
+    Pem,
+    #[strum(to_string = "bcrypt")]
+    Bcrypt,
+    #[strum(to_string = "crypt")]
+    Crypt,
+    #[strum(to_string = "Auto-detect (lenient)")]
+    Auto,
+}
+
+impl SelectFormEnum for Base64Variant {}
+
+impl From<Base64Variant> for String {
+    fn from(variant: Base64Variant) -> Self {
+        variant.to_string()
+    }
+}
+
+/// Variants tried in order when auto-detecting; the first one that decodes wins.
+const DETECTABLE_VARIANTS: [Base64Variant; 6] = [
+    Base64Variant::Standard,
+    Base64Variant::StandardUnpadded,
+    Base64Variant::UrlSafe,
+    Base64Variant::UrlSafeUnpadded,
+    Base64Variant::Bcrypt,
+    Base64Variant::Crypt,
+];
+
+impl Base64Variant {
+    /// Encodes the bytes; auto-detect mode encodes as standard Base64.
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Standard | Self::Auto => Base64::encode_string(bytes),
+            Self::StandardUnpadded => Base64Unpadded::encode_string(bytes),
+            Self::UrlSafe => Base64Url::encode_string(bytes),
+            Self::UrlSafeUnpadded => Base64UrlUnpadded::encode_string(bytes),
+            Self::Mime => wrap_lines(&Base64::encode_string(bytes), 76, "\r\n"),
+            Self::Pem => wrap_lines(&Base64::encode_string(bytes), 64, "\n"),
+            Self::Bcrypt => Base64Bcrypt::encode_string(bytes),
+            Self::Crypt => Base64ShaCrypt::encode_string(bytes),
+        }
+    }
+
+    /// Decodes the input and describes the variant that was used to decode it.
+    fn decode(&self, input: &str) -> Result<(Vec<u8>, String), base64ct::Error> {
+        let decoded = match self {
+            Self::Standard => Base64::decode_vec(input),
+            Self::StandardUnpadded => Base64Unpadded::decode_vec(input),
+            Self::UrlSafe => Base64Url::decode_vec(input),
+            Self::UrlSafeUnpadded => Base64UrlUnpadded::decode_vec(input),
+            Self::Mime | Self::Pem => Base64::decode_vec(&remove_whitespace(input)),
+            Self::Bcrypt => Base64Bcrypt::decode_vec(input),
+            Self::Crypt => Base64ShaCrypt::decode_vec(input),
+            Self::Auto => return decode_lenient(input),
+        };
+        decoded.map(|decoded| (decoded, self.to_string()))
+    }
+}
+
+/// Decodes Base64 ignoring whitespace, missing padding and mixed standard/URL-safe alphabets.
+pub(crate) fn decode_lenient(input: &str) -> Result<(Vec<u8>, String), base64ct::Error> {
+    let cleaned = remove_whitespace(input);
+    let line_width = input.trim().lines().next().map(str::len);
+    for variant in DETECTABLE_VARIANTS {
+        if let Ok((decoded, _)) = variant.decode(&cleaned) {
+            let variant = match (variant, input.trim().contains('\n'), line_width) {
+                (Base64Variant::Standard, true, Some(76)) => Base64Variant::Mime,
+                (Base64Variant::Standard, true, Some(64)) => Base64Variant::Pem,
+                _ => variant,
+            };
+            let description = match variant {
+                Base64Variant::Mime | Base64Variant::Pem => variant.to_string(),
+                _ if cleaned.len() == input.len() => variant.to_string(),
+                _ => format!("{variant}, whitespace ignored"),
+            };
+            return Ok((decoded, description));
+        }
+    }
+
+    let normalized: String = cleaned
+        .trim_end_matches('=')
+        .chars()
+        .map(|character| match character {
+            '-' => '+',
+            '_' => '/',
+            character => character,
+        })
+        .collect();
+    let alphabet = match (cleaned.contains(['+', '/']), cleaned.contains(['-', '_'])) {
+        (true, true) => "Mixed standard and URL-safe alphabets",
+        (false, true) => "URL-safe",
+        _ => "Standard",
+    };
+    let mut description = alphabet.to_string();
+    if cleaned.ends_with('=') {
+        description.push_str(", invalid padding ignored");
+    }
+    if cleaned.len() != input.len() {
+        description.push_str(", whitespace ignored");
+    }
+    Base64Unpadded::decode_vec(&normalized).map(|decoded| (decoded, description))
+}
+
+fn remove_whitespace(input: &str) -> String {
+    input
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect()
+}
+
+fn wrap_lines(encoded: &str, width: usize, line_ending: &str) -> String {
+    encoded
+        .as_bytes()
+        .chunks(width)
+        .map(|line| String::from_utf8_lossy(line))
+        .collect::<Vec<_>>()
+        .join(line_ending)
+}
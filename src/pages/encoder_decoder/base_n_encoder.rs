@@ -0,0 +1,477 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaCode;
+use sha2::{Digest, Sha256};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{SelectForm, SelectFormEnum};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Base-N Encoder / Decoder",
+    short_title: "Base-N",
+    description: "Encode and decode Base32, Base58, Ascii85, Z85, Base45 and Base36 strings",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaCode> = WidgetIcon { icon: FaCode };
+
+pub fn BaseNEncoder() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(EncoderValue {
+            text: EncoderText::default(),
+            encoding: BaseEncoding::default(),
+            error: None,
+        })
+    });
+    let encoding = value_context.read().encoding;
+    let error = value_context.read().error.clone();
+    let is_binary = value_context.read().text.is_binary();
+
+    rsx! {
+        div { class: "widget base-n-encoder",
+            div { class: "widget-params",
+                SelectForm::<BaseEncoding> {
+                    label: "Encoding",
+                    oninput: move |encoding: BaseEncoding| {
+                        value_context
+                            .with_mut(|value| {
+                                value.encoding = encoding;
+                                value.refresh();
+                            });
+                    },
+                    value: encoding,
+                }
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary,
+                encoded_label: "Encoded",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.encode();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary,
+                encoded_label: "Encoded",
+                oninput: move |input: String| value_context.with_mut(|value| value.decode(input)),
+            }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EncoderValue {
+    text: EncoderText,
+    encoding: BaseEncoding,
+    error: Option<String>,
+}
+
+impl EncoderValue {
+    fn encode(&mut self) {
+        match self.encoding.encode(&self.text.decoded_bytes) {
+            Ok(encoded) => {
+                self.text.encoded_value = encoded;
+                self.error = None;
+            }
+            Err(err) => {
+                self.text.encoded_value = String::new();
+                self.error = Some(err);
+            }
+        }
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn decode(&mut self, input: String) {
+        match self.encoding.decode(&input) {
+            Ok(bytes) => {
+                self.text.set_decoded_bytes(bytes);
+                self.error = None;
+            }
+            Err(err) => {
+                self.text.set_decoded_bytes(Vec::new());
+                self.error = Some(err);
+            }
+        }
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Re-encodes the text or re-decodes the encoded input, whichever was typed last, after
+    /// the encoding changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.encode(),
+            Direction::Decode => self.decode(self.text.encoded_value.clone()),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum BaseEncoding {
+    #[default]
+    #[strum(to_string = "Base32 (RFC 4648)")]
+    Base32,
+    #[strum(to_string = "Base32 (RFC 4648, unpadded)")]
+    Base32Unpadded,
+    #[strum(to_string = "Base32hex")]
+    Base32Hex,
+    #[strum(to_string = "Base32 (Crockford)")]
+    Base32Crockford,
+    #[strum(to_string = "Base58 (Bitcoin)")]
+    Base58Bitcoin,
+    #[strum(to_string = "Base58 (Flickr)")]
+    Base58Flickr,
+    #[strum(to_string = "Base58 (Ripple)")]
+    Base58Ripple,
+    #[strum(to_string = "Base58Check")]
+    Base58Check,
+    #[strum(to_string = "Ascii85")]
+    Ascii85,
+    #[strum(to_string = "Z85")]
+    Z85,
+    #[strum(to_string = "Base45")]
+    Base45,
+    #[strum(to_string = "Base36")]
+    Base36,
+}
+
+impl SelectFormEnum for BaseEncoding {}
+
+impl From<BaseEncoding> for String {
+    fn from(encoding: BaseEncoding) -> Self {
+        encoding.to_string()
+    }
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_HEX_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const BASE32_CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const BASE58_BITCOIN_ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE58_FLICKR_ALPHABET: &[u8; 58] =
+    b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+const BASE58_RIPPLE_ALPHABET: &[u8; 58] =
+    b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+const BASE45_ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+const BASE36_ALPHABET: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+impl BaseEncoding {
+    fn encode(&self, bytes: &[u8]) -> Result<String, String> {
+        Ok(match self {
+            Self::Base32 => encode_base32(bytes, BASE32_ALPHABET, true),
+            Self::Base32Unpadded => encode_base32(bytes, BASE32_ALPHABET, false),
+            Self::Base32Hex => encode_base32(bytes, BASE32_HEX_ALPHABET, true),
+            Self::Base32Crockford => encode_base32(bytes, BASE32_CROCKFORD_ALPHABET, false),
+            Self::Base58Bitcoin => encode_radix(bytes, BASE58_BITCOIN_ALPHABET),
+            Self::Base58Flickr => encode_radix(bytes, BASE58_FLICKR_ALPHABET),
+            Self::Base58Ripple => encode_radix(bytes, BASE58_RIPPLE_ALPHABET),
+            Self::Base58Check => {
+                let mut payload = bytes.to_vec();
+                payload.extend_from_slice(&base58_checksum(bytes));
+                encode_radix(&payload, BASE58_BITCOIN_ALPHABET)
+            }
+            Self::Ascii85 => encode_ascii85(bytes),
+            Self::Z85 => encode_z85(bytes)?,
+            Self::Base45 => encode_base45(bytes),
+            Self::Base36 => encode_radix(bytes, BASE36_ALPHABET),
+        })
+    }
+
+    fn decode(&self, input: &str) -> Result<Vec<u8>, String> {
+        // Space is a Base45 digit, so only line breaks and tabs are trimmed from Base45 input.
+        let input = match self {
+            Self::Base45 => input.trim_matches(['\r', '\n', '\t']),
+            _ => input.trim(),
+        };
+        match self {
+            Self::Base32 | Self::Base32Unpadded => {
+                decode_base32(&input.to_ascii_uppercase(), BASE32_ALPHABET)
+            }
+            Self::Base32Hex => decode_base32(&input.to_ascii_uppercase(), BASE32_HEX_ALPHABET),
+            Self::Base32Crockford => {
+                // Crockford decoding is case-insensitive, ignores hyphens and maps the
+                // ambiguous letters I, L and O to digits.
+                let normalized: String = input
+                    .chars()
+                    .filter(|character| *character != '-')
+                    .map(|character| match character.to_ascii_uppercase() {
+                        'I' | 'L' => '1',
+                        'O' => '0',
+                        character => character,
+                    })
+                    .collect();
+                decode_base32(&normalized, BASE32_CROCKFORD_ALPHABET)
+            }
+            Self::Base58Bitcoin => decode_radix(input, BASE58_BITCOIN_ALPHABET),
+            Self::Base58Flickr => decode_radix(input, BASE58_FLICKR_ALPHABET),
+            Self::Base58Ripple => decode_radix(input, BASE58_RIPPLE_ALPHABET),
+            Self::Base58Check => {
+                let decoded = decode_radix(input, BASE58_BITCOIN_ALPHABET)?;
+                if decoded.len() < 4 {
+                    return Err("Base58Check data is shorter than its 4 byte checksum.".into());
+                }
+                let (payload, checksum) = decoded.split_at(decoded.len() - 4);
+                if base58_checksum(payload) != checksum {
+                    return Err("Base58Check checksum does not match.".into());
+                }
+                Ok(payload.to_vec())
+            }
+            Self::Ascii85 => decode_ascii85(input),
+            Self::Z85 => decode_z85(input),
+            Self::Base45 => decode_base45(input),
+            Self::Base36 => decode_radix(&input.to_ascii_lowercase(), BASE36_ALPHABET),
+        }
+    }
+}
+
+fn alphabet_index(alphabet: &[u8], character: char) -> Result<u32, String> {
+    alphabet
+        .iter()
+        .position(|candidate| u32::from(*candidate) == u32::from(character))
+        .map(|index| index as u32)
+        .ok_or(format!("Invalid character '{character}'."))
+}
+
+fn encode_base32(bytes: &[u8], alphabet: &[u8; 32], padding: bool) -> String {
+    let mut encoded = String::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(char::from(alphabet[((buffer >> bits) & 0x1f) as usize]));
+        }
+    }
+    if bits > 0 {
+        encoded.push(char::from(
+            alphabet[((buffer << (5 - bits)) & 0x1f) as usize],
+        ));
+    }
+    if padding {
+        while !encoded.len().is_multiple_of(8) {
+            encoded.push('=');
+        }
+    }
+    encoded
+}
+
+fn decode_base32(input: &str, alphabet: &[u8; 32]) -> Result<Vec<u8>, String> {
+    let mut decoded = vec![];
+    let (mut buffer, mut bits) = (0u32, 0);
+    for character in input.trim_end_matches('=').chars() {
+        buffer = (buffer << 5) | alphabet_index(alphabet, character)?;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+        }
+    }
+    if bits >= 5 {
+        return Err("Invalid Base32 length.".into());
+    }
+    Ok(decoded)
+}
+
+/// Encodes the bytes as a big-endian number in the radix of the alphabet. Leading zero bytes
+/// are kept as leading zero digits, as in Base58.
+fn encode_radix(bytes: &[u8], alphabet: &[u8]) -> String {
+    let radix = alphabet.len() as u32;
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+    let mut digits: Vec<u32> = vec![];
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += *digit << 8;
+            *digit = carry % radix;
+            carry /= radix;
+        }
+        while carry > 0 {
+            digits.push(carry % radix);
+            carry /= radix;
+        }
+    }
+    std::iter::repeat_n(char::from(alphabet[0]), zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| char::from(alphabet[*digit as usize])),
+        )
+        .collect()
+}
+
+fn decode_radix(input: &str, alphabet: &[u8]) -> Result<Vec<u8>, String> {
+    let radix = alphabet.len() as u32;
+    let zero = char::from(alphabet[0]);
+    let zeros = input
+        .chars()
+        .take_while(|character| *character == zero)
+        .count();
+    let mut bytes: Vec<u8> = vec![];
+    for character in input.chars().skip(zeros) {
+        let mut carry = alphabet_index(alphabet, character)?;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * radix;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    Ok(std::iter::repeat_n(0, zeros)
+        .chain(bytes.into_iter().rev())
+        .collect())
+}
+
+/// The first four bytes of the double SHA-256 of the payload.
+fn base58_checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(payload));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn encode_ascii85(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(4) {
+        if chunk == [0, 0, 0, 0] {
+            encoded.push('z');
+            continue;
+        }
+        let mut group = [0u8; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let digits = base85_digits(u32::from_be_bytes(group));
+        for digit in &digits[..chunk.len() + 1] {
+            encoded.push(char::from(b'!' + digit));
+        }
+    }
+    encoded
+}
+
+fn decode_ascii85(input: &str) -> Result<Vec<u8>, String> {
+    let input = input.strip_prefix("<~").unwrap_or(input);
+    let input = input.strip_suffix("~>").unwrap_or(input);
+    let mut decoded = vec![];
+    let mut group: Vec<u8> = vec![];
+    for character in input.chars().filter(|character| !character.is_whitespace()) {
+        match character {
+            'z' if group.is_empty() => decoded.extend([0; 4]),
+            '!'..='u' => {
+                group.push(character as u8 - b'!');
+                if group.len() == 5 {
+                    decoded.extend(base85_value(&group)?.to_be_bytes());
+                    group.clear();
+                }
+            }
+            _ => return Err(format!("Invalid character '{character}'.")),
+        }
+    }
+    match group.len() {
+        0 => {}
+        1 => return Err("Invalid Ascii85 length.".into()),
+        len => {
+            let padding = 5 - len;
+            group.resize(5, 84);
+            decoded.extend(&base85_value(&group)?.to_be_bytes()[..4 - padding]);
+        }
+    }
+    Ok(decoded)
+}
+
+fn encode_z85(bytes: &[u8]) -> Result<String, String> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err("Z85 can only encode data whose length is a multiple of 4 bytes.".into());
+    }
+    Ok(bytes
+        .chunks(4)
+        .flat_map(|chunk| {
+            base85_digits(u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        })
+        .map(|digit| char::from(Z85_ALPHABET[usize::from(digit)]))
+        .collect())
+}
+
+fn decode_z85(input: &str) -> Result<Vec<u8>, String> {
+    if !input.len().is_multiple_of(5) {
+        return Err("Z85 data length must be a multiple of 5 characters.".into());
+    }
+    let digits = input
+        .chars()
+        .map(|character| alphabet_index(Z85_ALPHABET, character).map(|digit| digit as u8))
+        .collect::<Result<Vec<u8>, String>>()?;
+    let mut decoded = vec![];
+    for group in digits.chunks(5) {
+        decoded.extend(base85_value(group)?.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+fn base85_digits(mut value: u32) -> [u8; 5] {
+    let mut digits = [0u8; 5];
+    for digit in digits.iter_mut().rev() {
+        *digit = (value % 85) as u8;
+        value /= 85;
+    }
+    digits
+}
+
+fn base85_value(digits: &[u8]) -> Result<u32, String> {
+    digits
+        .iter()
+        .try_fold(0u32, |value, digit| {
+            value.checked_mul(85)?.checked_add(u32::from(*digit))
+        })
+        .ok_or("Base85 group is out of range.".to_string())
+}
+
+fn encode_base45(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(2) {
+        let (mut value, length) = match chunk {
+            [first, second] => (u32::from(*first) * 256 + u32::from(*second), 3),
+            _ => (u32::from(chunk[0]), 2),
+        };
+        for _ in 0..length {
+            encoded.push(char::from(BASE45_ALPHABET[(value % 45) as usize]));
+            value /= 45;
+        }
+    }
+    encoded
+}
+
+fn decode_base45(input: &str) -> Result<Vec<u8>, String> {
+    let digits = input
+        .chars()
+        .map(|character| alphabet_index(BASE45_ALPHABET, character))
+        .collect::<Result<Vec<u32>, String>>()?;
+    let mut decoded = vec![];
+    for group in digits.chunks(3) {
+        let value = group
+            .iter()
+            .rev()
+            .fold(0, |value, digit| value * 45 + digit);
+        match group.len() {
+            3 if value <= 0xffff => decoded.extend((value as u16).to_be_bytes()),
+            2 if value <= 0xff => decoded.push(value as u8),
+            _ => return Err("Invalid Base45 data.".into()),
+        }
+    }
+    Ok(decoded)
+}
@@ -4,12 +4,14 @@ use std::{
     str::FromStr,
 };
 
-use cidr::{Family, IpCidr};
+use cidr::{Family, IpCidr, IpInet};
 use dioxus::prelude::*;
 use dioxus_free_icons::icons::fa_solid_icons::FaNetworkWired;
+use num_bigint::BigUint;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
 
 use crate::{
-    components::inputs::{TextAreaForm, TextInput},
+    components::inputs::{SelectForm, SelectFormEnum, TextAreaForm, TextInput},
     pages::{WidgetEntry, WidgetIcon},
     utils::add_number_delimiters,
 };
@@ -17,7 +19,8 @@ use crate::{
 pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
     title: "CIDR Decoder",
     short_title: "CIDR",
-    description: "Decode Classless Inter-Domain Routing (CIDR) notation to IP address range",
+    description:
+        "Decode CIDR notation, aggregate IP lists into CIDRs and convert IP ranges to CIDRs",
     icon: move || ICON.icon(),
 };
 
@@ -26,10 +29,37 @@ const ICON: WidgetIcon<FaNetworkWired> = WidgetIcon {
 };
 
 pub fn CidrDecoder() -> Element {
+    let mut cidr_mode = use_signal(CidrMode::default);
+
+    rsx! {
+        div { class: "widget",
+            SelectForm::<CidrMode> {
+                label: "Mode",
+                oninput: move |mode: CidrMode| cidr_mode.set(mode),
+                value: cidr_mode(),
+            }
+            match cidr_mode() {
+                CidrMode::Decode => rsx! {
+                    CidrDescription {}
+                },
+                CidrMode::Aggregate => rsx! {
+                    CidrAggregator {}
+                },
+                CidrMode::RangeToCidr => rsx! {
+                    RangeToCidr {}
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn CidrDescription() -> Element {
     let mut cidr_ref =
         use_signal(|| IpCidr::new(std::net::IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0).unwrap());
 
     let mut cidr_input_ref = use_signal(|| cidr_ref.with(|cidr| cidr.to_string()));
+    let mut mac_input = use_signal(String::new);
 
     let cidr_description = cidr_ref.with(|cidr| {
         let mut description = String::new();
@@ -49,65 +79,471 @@ pub fn CidrDecoder() -> Element {
         ));
         description.push_str(&format!("First IP: {}\n", cidr.first_address()));
         description.push_str(&format!("Last IP: {}\n", cidr.last_address()));
-        description.push_str(&format!("Total Addresses: {}\n", {
-            const BASE: u128 = 2;
-            let power = match cidr.family() {
-                Family::Ipv4 => 32,
-                Family::Ipv6 => 128,
-            } - u32::from(cidr.network_length());
-
-            if power == 128 {
-                // This is too big to fit in a u128, so we have to hardcode it or use a non-std u256 crate.
-                "340,282,366,920,938,463,463,374,607,431,768,211,456".to_string()
-            } else {
-                add_number_delimiters(BASE.pow(power).to_string(), ',', 3)
+        description.push_str(&format!(
+            "Total Addresses: {}\n",
+            add_number_delimiters(address_count(host_bits(cidr)).to_string(), ',', 3)
+        ));
+        description.push_str(&format!("Netmask (hex): {}\n", hex_mask(cidr)));
+        description.push_str(&format!("Netmask (binary): {}\n", binary_mask(cidr)));
+        if let IpAddr::V6(address) = cidr.first_address() {
+            description.push_str(&format!("Expanded: {}\n", expanded_ipv6(&address)));
+            description.push_str(&format!("Compressed: {address}\n"));
+        }
+        description.push_str(&format!("Reverse DNS Zone: {}\n", reverse_dns_zone(cidr)));
+        for (label, embedded) in embedded_ipv4(cidr) {
+            description.push_str(&format!("{label}: {embedded}\n"));
+        }
+        if let (IpAddr::V6(prefix), Some(mac)) =
+            (cidr.first_address(), parse_mac(&mac_input.read()))
+        {
+            let interface_id = eui64_interface_id(mac);
+            description.push_str(&format!(
+                "EUI-64 Interface ID: {}\n",
+                interface_id
+                    .chunks(2)
+                    .map(|pair| format!("{:02x}{:02x}", pair[0], pair[1]))
+                    .collect::<Vec<_>>()
+                    .join(":")
+            ));
+            if cidr.network_length() <= 64 {
+                let address = u128::from(prefix) | u128::from(u64::from_be_bytes(interface_id));
+                description.push_str(&format!("SLAAC Address: {}\n", Ipv6Addr::from(address)));
             }
-        }));
+        }
         description
     });
 
     let mut show_error_state = use_signal(|| false);
     rsx! {
-        div { class: "widget",
-            TextInput {
-                label: "CIDR",
-                value: "{cidr_input_ref.with(|cidr_str| cidr_str.to_string())}",
-                oninput: move |event: Event<FormData>| {
-                    let cidr = event.value();
-                    let cidr_clone = cidr.clone();
-                    let cidr_trim = cidr.trim();
-                    tracing::info!("CIDR: {}", cidr);
-                    cidr_input_ref
-                        .with_mut(|cidr_input| {
-                            *cidr_input = cidr_clone;
+        TextInput {
+            label: "CIDR",
+            value: "{cidr_input_ref.with(|cidr_str| cidr_str.to_string())}",
+            oninput: move |event: Event<FormData>| {
+                let cidr = event.value();
+                let cidr_clone = cidr.clone();
+                let cidr_trim = cidr.trim();
+                tracing::info!("CIDR: {}", cidr);
+                cidr_input_ref
+                    .with_mut(|cidr_input| {
+                        *cidr_input = cidr_clone;
+                    });
+                if let Ok(cidr_valid) = IpCidr::from_str(cidr_trim) {
+                    cidr_ref
+                        .with_mut(|cidr_obj| {
+                            *cidr_obj = cidr_valid;
+                            show_error_state
+                                .with_mut(|show_error_state| {
+                                    *show_error_state = false;
+                                });
                         });
-                    if let Ok(cidr_valid) = IpCidr::from_str(cidr_trim) {
-                        cidr_ref
-                            .with_mut(|cidr_obj| {
-                                *cidr_obj = cidr_valid;
-                                show_error_state
-                                    .with_mut(|show_error_state| {
-                                        *show_error_state = false;
-                                    });
-                            });
-                    } else {
-                        show_error_state
-                            .with_mut(|show_error_state| {
-                                *show_error_state = true;
-                            });
+                } else {
+                    show_error_state
+                        .with_mut(|show_error_state| {
+                            *show_error_state = true;
+                        });
+                }
+            },
+        }
+        div {
+            class: "alert alert-warning m-0",
+            hidden: !*show_error_state.read(),
+            "The provided CIDR is invalid."
+        }
+        if cidr_ref.read().is_ipv6() {
+            TextInput {
+                label: "MAC Address (for EUI-64)",
+                value: "{mac_input}",
+                oninput: move |event: Event<FormData>| mac_input.set(event.value()),
+            }
+            if !mac_input.read().trim().is_empty() && parse_mac(&mac_input.read()).is_none() {
+                div { class: "alert alert-warning m-0", "The provided MAC address is invalid." }
+            }
+        }
+        TextAreaForm {
+            label: "Description",
+            readonly: true,
+            value: "{cidr_description}",
+        }
+    }
+}
+
+#[component]
+fn CidrAggregator() -> Element {
+    let mut entries = use_signal(|| {
+        "10.0.0.0/24\n10.0.1.0/24\n10.0.2.5\n10.0.2.0-10.0.2.255\n192.168.1.10-192.168.1.20\n2001:db8::/33\n2001:db8:8000::/33".to_string()
+    });
+
+    let aggregated = parse_address_ranges(&entries.read()).map(|ranges| {
+        let input_count = ranges.len();
+        let cidrs: Vec<IpCidr> = merge_address_ranges(ranges)
+            .into_iter()
+            .flat_map(|range| range.to_cidrs())
+            .collect();
+        (input_count, cidrs)
+    });
+
+    rsx! {
+        TextAreaForm {
+            label: "IPs, Ranges and CIDRs",
+            value: "{entries}",
+            oninput: move |event: Event<FormData>| entries.set(event.value()),
+        }
+        match aggregated {
+            Ok((input_count, cidrs)) => rsx! {
+                div { class: "widget-params",
+                    TextInput {
+                        label: "Input Entries",
+                        value: "{input_count}",
+                        readonly: true,
                     }
-                },
+                    TextInput {
+                        label: "Aggregated CIDRs",
+                        value: "{cidrs.len()}",
+                        readonly: true,
+                    }
+                }
+                TextAreaForm {
+                    label: "Minimal CIDR List",
+                    readonly: true,
+                    value: format_cidr_list(&cidrs),
+                }
+            },
+            Err(err) => rsx! {
+                div { class: "alert alert-warning m-0", "{err}" }
+            },
+        }
+    }
+}
+
+#[component]
+fn RangeToCidr() -> Element {
+    let mut first_input = use_signal(|| "192.168.0.10".to_string());
+    let mut last_input = use_signal(|| "192.168.1.20".to_string());
+
+    let cidrs = AddressRange::parse(&format!("{}-{}", first_input.read(), last_input.read()))
+        .map(|range| range.to_cidrs());
+
+    rsx! {
+        div { class: "widget-params",
+            TextInput {
+                label: "First IP",
+                value: "{first_input}",
+                oninput: move |event: Event<FormData>| first_input.set(event.value()),
             }
-            div {
-                class: "alert alert-warning m-0",
-                hidden: !*show_error_state.read(),
-                "The provided CIDR is invalid."
+            TextInput {
+                label: "Last IP",
+                value: "{last_input}",
+                oninput: move |event: Event<FormData>| last_input.set(event.value()),
             }
-            TextAreaForm {
-                label: "Description",
-                readonly: true,
-                value: "{cidr_description}",
+        }
+        match cidrs {
+            Ok(cidrs) => rsx! {
+                TextInput {
+                    label: "CIDRs",
+                    value: "{cidrs.len()}",
+                    readonly: true,
+                }
+                TextAreaForm {
+                    label: "Exact CIDR List",
+                    readonly: true,
+                    value: format_cidr_list(&cidrs),
+                }
+            },
+            Err(err) => rsx! {
+                div { class: "alert alert-warning m-0", "{err}" }
+            },
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum CidrMode {
+    #[default]
+    Decode,
+    #[strum(to_string = "Aggregate IPs, Ranges and CIDRs")]
+    Aggregate,
+    #[strum(to_string = "Range to CIDR")]
+    RangeToCidr,
+}
+
+impl SelectFormEnum for CidrMode {}
+
+impl From<CidrMode> for String {
+    fn from(mode: CidrMode) -> Self {
+        mode.to_string()
+    }
+}
+
+/// An inclusive range of addresses within one family, as integers.
+#[derive(Clone, Debug, PartialEq)]
+struct AddressRange {
+    family: Family,
+    first: u128,
+    last: u128,
+}
+
+impl AddressRange {
+    /// Parses a single address, a CIDR (host bits are ignored) or a `first-last` range.
+    fn parse(entry: &str) -> Result<Self, String> {
+        let entry = entry.trim();
+        if let Some((first, last)) = entry.split_once('-') {
+            let first = IpAddr::from_str(first.trim())
+                .map_err(|_| format!("\"{entry}\" does not start with a valid IP address."))?;
+            let last = IpAddr::from_str(last.trim())
+                .map_err(|_| format!("\"{entry}\" does not end with a valid IP address."))?;
+            if first.is_ipv4() != last.is_ipv4() {
+                return Err(format!("\"{entry}\" mixes IPv4 and IPv6 addresses."));
+            }
+            let range = Self {
+                family: IpCidr::new_host(first).family(),
+                first: address_to_u128(first),
+                last: address_to_u128(last),
+            };
+            if range.first > range.last {
+                return Err(format!("\"{entry}\" ends before it starts."));
+            }
+            return Ok(range);
+        }
+        let cidr = IpInet::from_str(entry)
+            .map(|inet| inet.network())
+            .map_err(|_| format!("\"{entry}\" is not an IP address, range or CIDR."))?;
+        Ok(Self {
+            family: cidr.family(),
+            first: address_to_u128(cidr.first_address()),
+            last: address_to_u128(cidr.last_address()),
+        })
+    }
+
+    /// Splits the range into the fewest CIDRs that cover exactly its addresses.
+    fn to_cidrs(&self) -> Vec<IpCidr> {
+        let max_bits = u32::from(self.family.len());
+        let mut cidrs = Vec::new();
+        let mut first = self.first;
+        loop {
+            // The block may neither break the alignment of `first` nor run past `last`.
+            let remaining = (self.last - first).checked_add(1);
+            let size_bits = remaining.map_or(128, |remaining| remaining.ilog2());
+            let bits = first.trailing_zeros().min(size_bits).min(max_bits);
+            let length = self.family.len() - bits as u8;
+            cidrs.push(
+                IpCidr::new(address_from_u128(first, &self.family), length)
+                    .expect("blocks are aligned to their prefix length"),
+            );
+            let block_last = first | 1u128.checked_shl(bits).map_or(u128::MAX, |size| size - 1);
+            match block_last < self.last {
+                true => first = block_last + 1,
+                false => return cidrs,
+            }
+        }
+    }
+}
+
+/// Parses one entry per line or separated by commas or spaces; `#` starts a comment.
+fn parse_address_ranges(input: &str) -> Result<Vec<AddressRange>, String> {
+    input
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split([',', ';']))
+        .flat_map(|entry| {
+            // Allow spaces around the dash of a range but split other whitespace.
+            let entry = entry.replace(" -", "-").replace("- ", "-");
+            entry
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .map(|entry| AddressRange::parse(&entry))
+        .collect()
+}
+
+/// Merges overlapping and adjacent ranges, IPv4 before IPv6.
+fn merge_address_ranges(mut ranges: Vec<AddressRange>) -> Vec<AddressRange> {
+    ranges.sort_by_key(|range| (range.family.clone(), range.first));
+    let mut merged: Vec<AddressRange> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(previous)
+                if previous.family == range.family
+                    && previous
+                        .last
+                        .checked_add(1)
+                        .is_none_or(|next| next >= range.first) =>
+            {
+                previous.last = previous.last.max(range.last);
             }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+fn format_cidr_list(cidrs: &[IpCidr]) -> String {
+    cidrs
+        .iter()
+        .map(|cidr| format!("{cidr:#}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn address_to_u128(address: IpAddr) -> u128 {
+    match address {
+        IpAddr::V4(address) => u128::from(u32::from(address)),
+        IpAddr::V6(address) => u128::from(address),
+    }
+}
+
+pub(crate) fn address_from_u128(address: u128, family: &Family) -> IpAddr {
+    match family {
+        Family::Ipv4 => IpAddr::V4(Ipv4Addr::from(address as u32)),
+        Family::Ipv6 => IpAddr::V6(Ipv6Addr::from(address)),
+    }
+}
+
+pub(crate) fn host_bits(cidr: &IpCidr) -> u32 {
+    u32::from(cidr.family().len() - cidr.network_length())
+}
+
+/// Counts the addresses in a block with the given number of host bits; an IPv6 /0 holds
+/// 2^128 addresses, one more than fits in a `u128`.
+pub(crate) fn address_count(host_bits: u32) -> BigUint {
+    BigUint::from(1u8) << host_bits
+}
+
+fn hex_mask(cidr: &IpCidr) -> String {
+    match cidr.mask() {
+        IpAddr::V4(mask) => format!("0x{:08X}", u32::from(mask)),
+        IpAddr::V6(mask) => format!("0x{:032X}", u128::from(mask)),
+    }
+}
+
+/// Writes the mask in binary, grouped like the address: octets for IPv4, hextets for IPv6.
+fn binary_mask(cidr: &IpCidr) -> String {
+    match cidr.mask() {
+        IpAddr::V4(mask) => mask
+            .octets()
+            .iter()
+            .map(|octet| format!("{octet:08b}"))
+            .collect::<Vec<_>>()
+            .join("."),
+        IpAddr::V6(mask) => mask
+            .segments()
+            .iter()
+            .map(|segment| format!("{segment:016b}"))
+            .collect::<Vec<_>>()
+            .join(":"),
+    }
+}
+
+pub(crate) fn expanded_ipv6(address: &Ipv6Addr) -> String {
+    address
+        .segments()
+        .iter()
+        .map(|segment| format!("{segment:04x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Names the reverse DNS zone covering the block. Zones are delegated per octet (IPv4) or
+/// nibble (IPv6), so prefixes in between are rounded down to the enclosing zone.
+pub(crate) fn reverse_dns_zone(cidr: &IpCidr) -> String {
+    let length = usize::from(cidr.network_length());
+    let (labels, suffix, zone_length) = match cidr.first_address() {
+        IpAddr::V4(address) => (
+            address
+                .octets()
+                .iter()
+                .take(length / 8)
+                .map(|octet| octet.to_string())
+                .collect::<Vec<_>>(),
+            "in-addr.arpa",
+            length / 8 * 8,
+        ),
+        IpAddr::V6(address) => (
+            address
+                .octets()
+                .iter()
+                .flat_map(|octet| [octet >> 4, octet & 0x0F])
+                .take(length / 4)
+                .map(|nibble| format!("{nibble:x}"))
+                .collect::<Vec<_>>(),
+            "ip6.arpa",
+            length / 4 * 4,
+        ),
+    };
+    let zone = labels
+        .iter()
+        .rev()
+        .map(String::as_str)
+        .chain([suffix])
+        .collect::<Vec<_>>()
+        .join(".");
+    match zone_length == length {
+        true => zone,
+        false => format!("{zone} (enclosing /{zone_length})"),
+    }
+}
+
+/// Extracts IPv4 addresses embedded in IPv4-mapped, 6to4, NAT64 and Teredo addresses.
+fn embedded_ipv4(cidr: &IpCidr) -> Vec<(&'static str, Ipv4Addr)> {
+    let IpAddr::V6(address) = cidr.first_address() else {
+        return Vec::new();
+    };
+    let bits = u128::from(address);
+    let within = |prefix: &str| {
+        IpCidr::from_str(prefix).is_ok_and(|prefix| {
+            prefix.contains(&cidr.first_address())
+                && prefix.network_length() <= cidr.network_length()
+        })
+    };
+    let mut embedded = Vec::new();
+    if within("::ffff:0:0/96") {
+        embedded.push(("IPv4-Mapped Address", Ipv4Addr::from(bits as u32)));
+    }
+    if within("64:ff9b::/96") {
+        embedded.push(("NAT64 IPv4 Address", Ipv4Addr::from(bits as u32)));
+    }
+    if within("2002::/16") && cidr.network_length() >= 48 {
+        embedded.push(("6to4 IPv4 Address", Ipv4Addr::from((bits >> 80) as u32)));
+    }
+    if within("2001::/32") {
+        embedded.push(("Teredo Server", Ipv4Addr::from((bits >> 64) as u32)));
+        if cidr.network_length() == 128 {
+            embedded.push(("Teredo Client", Ipv4Addr::from(!(bits as u32))));
         }
     }
+    embedded
+}
+
+/// Parses a MAC address written with `:`, `-` or `.` separators or none at all.
+pub(crate) fn parse_mac(input: &str) -> Option<[u8; 6]> {
+    let digits: String = input
+        .trim()
+        .chars()
+        .filter(|character| !matches!(character, ':' | '-' | '.' | ' '))
+        .collect();
+    if digits.len() != 12 {
+        return None;
+    }
+    let mut mac = [0u8; 6];
+    for (index, byte) in mac.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(digits.get(index * 2..index * 2 + 2)?, 16).ok()?;
+    }
+    Some(mac)
+}
+
+/// Derives the modified EUI-64 interface ID (RFC 4291 appendix A): `FF:FE` goes in the middle
+/// and the universal/local bit is flipped.
+pub(crate) fn eui64_interface_id(mac: [u8; 6]) -> [u8; 8] {
+    [
+        mac[0] ^ 0x02,
+        mac[1],
+        mac[2],
+        0xFF,
+        0xFE,
+        mac[3],
+        mac[4],
+        mac[5],
+    ]
 }
@@ -0,0 +1,408 @@
+#![allow(non_snake_case)]
+use base64ct::{Base64, Encoding};
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaFileZipper;
+use flate2::{
+    read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder},
+    write::{DeflateEncoder, GzEncoder, ZlibEncoder},
+    Compression,
+};
+use std::io::{Read, Write};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::decoded_bytes::DecodedBytesView;
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{
+    FileInput, LoadedFile, SelectForm, SelectFormEnum, SwitchInput, TextInput,
+};
+use crate::pages::encoder_decoder::{base64_encoder::decode_lenient, hex_encoder::decode_hex};
+use crate::pages::{WidgetEntry, WidgetIcon};
+use crate::utils::add_number_delimiters;
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Compression Encoder / Decoder",
+    short_title: "Compression",
+    description: "Compress and decompress gzip, zlib, deflate, brotli, zstd and LZ4 data",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaFileZipper> = WidgetIcon { icon: FaFileZipper };
+
+/// Decompression stops here so that a small zip bomb cannot exhaust memory.
+const MAX_DECOMPRESSED_SIZE: u64 = 64 * 1024 * 1024;
+
+pub fn Compressor() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(EncoderValue {
+            text: EncoderText::default(),
+            compressed_bytes: Vec::new(),
+            format: CompressionFormat::default(),
+            encoding: CompressedEncoding::default(),
+            auto_detect: true,
+            detected_format: None,
+            error: None,
+        })
+    });
+    let format = value_context.read().format;
+    let encoding = value_context.read().encoding;
+    let auto_detect = value_context.read().auto_detect;
+    let detected_format = value_context.read().detected_format;
+    let error = value_context.read().error.clone();
+    let is_binary = value_context.read().text.is_binary();
+    let original_size = value_context.read().text.decoded_bytes.len();
+    let compressed_size = value_context.read().compressed_bytes.len();
+
+    rsx! {
+        div { class: "widget",
+            div { class: "widget-params",
+                SelectForm::<CompressionFormat> {
+                    label: "Format",
+                    oninput: move |format: CompressionFormat| {
+                        value_context
+                            .with_mut(|value| {
+                                value.format = format;
+                                value.refresh();
+                            });
+                    },
+                    value: format,
+                }
+                SelectForm::<CompressedEncoding> {
+                    label: "Compressed Data Encoding",
+                    oninput: move |encoding: CompressedEncoding| {
+                        value_context
+                            .with_mut(|value| {
+                                value.encoding = encoding;
+                                value.refresh();
+                            });
+                    },
+                    value: encoding,
+                }
+                if auto_detect {
+                    if let Some(detected_format) = detected_format {
+                        TextInput {
+                            label: "Detected Format",
+                            value: "{detected_format}",
+                            readonly: true,
+                        }
+                    }
+                }
+            }
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Auto-detect format when decompressing",
+                    checked: auto_detect,
+                    oninput: move |is_enabled| {
+                        value_context
+                            .with_mut(|value| {
+                                value.auto_detect = is_enabled;
+                                value.refresh();
+                            });
+                    },
+                }
+            }
+            FileInput {
+                label: "Compress File",
+                onload: move |file: LoadedFile| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(file.bytes);
+                            value.compress();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary,
+                encoded_label: "Compressed",
+                binary_label: "Decompressed Bytes (hex dump)",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.compress();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary,
+                encoded_label: "Compressed",
+                binary_label: "Decompressed Bytes (hex dump)",
+                oninput: move |input: String| value_context.with_mut(|value| value.decompress(input)),
+            }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            if original_size > 0 || compressed_size > 0 {
+                div { class: "widget-params",
+                    TextInput {
+                        label: "Original Size",
+                        value: "{add_number_delimiters(original_size.to_string(), ',', 3)} bytes",
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Compressed Size",
+                        value: "{add_number_delimiters(compressed_size.to_string(), ',', 3)} bytes",
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Ratio",
+                        value: compression_ratio(original_size, compressed_size),
+                        readonly: true,
+                    }
+                }
+            }
+            DecodedBytesView { bytes: value_context.read().text.decoded_bytes.clone() }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EncoderValue {
+    text: EncoderText,
+    compressed_bytes: Vec<u8>,
+    format: CompressionFormat,
+    encoding: CompressedEncoding,
+    auto_detect: bool,
+    detected_format: Option<CompressionFormat>,
+    error: Option<String>,
+}
+
+impl EncoderValue {
+    fn compress(&mut self) {
+        self.detected_format = None;
+        match self.format.compress(&self.text.decoded_bytes) {
+            Ok(compressed) => {
+                self.text.encoded_value = self.encoding.encode(&compressed);
+                self.compressed_bytes = compressed;
+                self.error = None;
+            }
+            Err(err) => {
+                self.text.encoded_value = String::new();
+                self.compressed_bytes.clear();
+                self.error = Some(err);
+            }
+        }
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn decompress(&mut self, input: String) {
+        let result = self.encoding.decode(&input).and_then(|compressed| {
+            let decompressed = match self.auto_detect {
+                true => decompress_auto(&compressed),
+                false => self
+                    .format
+                    .decompress(&compressed)
+                    .map(|decompressed| (decompressed, self.format)),
+            }?;
+            Ok((compressed, decompressed))
+        });
+        match result {
+            Ok((compressed, (decompressed, format))) => {
+                self.compressed_bytes = compressed;
+                self.text.set_decoded_bytes(decompressed);
+                self.detected_format = Some(format);
+                self.error = None;
+            }
+            Err(err) => {
+                self.compressed_bytes.clear();
+                self.text.set_decoded_bytes(Vec::new());
+                self.detected_format = None;
+                self.error = Some(err);
+            }
+        }
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Recompresses the text or decompresses the compressed input, whichever was typed last,
+    /// after an option changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.compress(),
+            Direction::Decode => self.decompress(self.text.encoded_value.clone()),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum CompressionFormat {
+    #[default]
+    Gzip,
+    Zlib,
+    #[strum(to_string = "Deflate (raw)")]
+    Deflate,
+    Brotli,
+    Zstd,
+    #[strum(to_string = "LZ4 (frame)")]
+    Lz4,
+}
+
+impl SelectFormEnum for CompressionFormat {}
+
+impl From<CompressionFormat> for String {
+    fn from(format: CompressionFormat) -> Self {
+        format.to_string()
+    }
+}
+
+impl CompressionFormat {
+    /// Recognizes the formats that start with magic bytes. Raw deflate and brotli streams
+    /// have no header.
+    fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0x1F, 0x8B, ..] => Some(Self::Gzip),
+            [0x28, 0xB5, 0x2F, 0xFD, ..] => Some(Self::Zstd),
+            [0x04, 0x22, 0x4D, 0x18, ..] => Some(Self::Lz4),
+            [method, flags, ..]
+                if method & 0x0F == 8
+                    && (u16::from(*method) * 256 + u16::from(*flags)) % 31 == 0 =>
+            {
+                Some(Self::Zlib)
+            }
+            _ => None,
+        }
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let compressed = match self {
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(bytes).and_then(|_| encoder.finish())
+            }
+            Self::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(bytes).and_then(|_| encoder.finish())
+            }
+            Self::Deflate => {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(bytes).and_then(|_| encoder.finish())
+            }
+            Self::Brotli => {
+                let mut compressed = Vec::new();
+                brotli::BrotliCompress(&mut &bytes[..], &mut compressed, &Default::default())
+                    .map(|_| compressed)
+            }
+            Self::Zstd => Ok(ruzstd::encoding::compress_to_vec(
+                bytes,
+                ruzstd::encoding::CompressionLevel::Fastest,
+            )),
+            Self::Lz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder
+                    .write_all(bytes)
+                    .map_err(lz4_flex::frame::Error::from)
+                    .and_then(|_| encoder.finish())
+                    .map_err(std::io::Error::other)
+            }
+        };
+        compressed.map_err(|err| format!("Failed to compress: {err}"))
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Gzip => read_limited(MultiGzDecoder::new(bytes)),
+            Self::Zlib => read_limited(ZlibDecoder::new(bytes)),
+            Self::Deflate => read_limited(DeflateDecoder::new(bytes)),
+            Self::Brotli => read_limited(brotli::Decompressor::new(bytes, 4096)),
+            Self::Zstd => match ruzstd::decoding::StreamingDecoder::new(bytes) {
+                Ok(decoder) => read_limited(decoder),
+                Err(err) => Err(err.to_string()),
+            },
+            Self::Lz4 => read_limited(lz4_flex::frame::FrameDecoder::new(bytes)),
+        }
+        .map_err(|err| format!("Invalid {self} data: {err}"))
+    }
+}
+
+/// Decompresses using the format given by the magic bytes, falling back to trying the
+/// headerless formats.
+fn decompress_auto(bytes: &[u8]) -> Result<(Vec<u8>, CompressionFormat), String> {
+    if let Some(format) = CompressionFormat::detect(bytes) {
+        return format
+            .decompress(bytes)
+            .map(|decompressed| (decompressed, format));
+    }
+    [CompressionFormat::Deflate, CompressionFormat::Brotli]
+        .into_iter()
+        .find_map(|format| {
+            format
+                .decompress(bytes)
+                .ok()
+                .map(|decompressed| (decompressed, format))
+        })
+        .ok_or_else(|| {
+            let formats: Vec<String> = CompressionFormat::iter().map(String::from).collect();
+            format!(
+                "Data does not look like any of {}; check the encoding",
+                formats.join(", ")
+            )
+        })
+}
+
+fn read_limited(reader: impl Read) -> Result<Vec<u8>, String> {
+    let mut decompressed = Vec::new();
+    reader
+        .take(MAX_DECOMPRESSED_SIZE + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(|err| err.to_string())?;
+    match decompressed.len() as u64 > MAX_DECOMPRESSED_SIZE {
+        true => Err(format!(
+            "output exceeds {} MiB",
+            MAX_DECOMPRESSED_SIZE / 1024 / 1024
+        )),
+        false => Ok(decompressed),
+    }
+}
+
+fn compression_ratio(original_size: usize, compressed_size: usize) -> String {
+    match (original_size, compressed_size) {
+        (0, _) | (_, 0) => "-".to_string(),
+        _ => format!(
+            "{:.1}% of original ({:.2}:1)",
+            compressed_size as f64 / original_size as f64 * 100.0,
+            original_size as f64 / compressed_size as f64
+        ),
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum CompressedEncoding {
+    #[default]
+    Base64,
+    Hex,
+}
+
+impl SelectFormEnum for CompressedEncoding {}
+
+impl From<CompressedEncoding> for String {
+    fn from(encoding: CompressedEncoding) -> Self {
+        encoding.to_string()
+    }
+}
+
+impl CompressedEncoding {
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Base64 => Base64::encode_string(bytes),
+            Self::Hex => bytes.iter().map(|byte| format!("{byte:02x}")).collect(),
+        }
+    }
+
+    /// Decodes Base64 in any alphabet or hex in any of the Hex widget's formats.
+    fn decode(&self, input: &str) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Base64 => decode_lenient(input)
+                .map(|(decoded, _)| decoded)
+                .map_err(|err| format!("Invalid Base64: {err}")),
+            Self::Hex => decode_hex(input),
+        }
+    }
+}
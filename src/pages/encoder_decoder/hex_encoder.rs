@@ -0,0 +1,459 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaMicrochip;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::decoded_bytes::DecodedBytesView;
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{
+    FileInput, LoadedFile, NumberInput, SelectForm, SelectFormEnum, SwitchInput,
+};
+use crate::pages::{WidgetEntry, WidgetIcon};
+use crate::utils::{ascii_gutter, hex_dump_with};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Hex Encoder / Decoder",
+    short_title: "Hex",
+    description: "Convert text and files to hex strings, arrays and hex dumps",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaMicrochip> = WidgetIcon { icon: FaMicrochip };
+
+/// Repeated dump rows are expanded up to this size so that a tiny dump cannot exhaust memory.
+const MAX_DUMP_SIZE: usize = 64 * 1024 * 1024;
+
+pub fn HexEncoder() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(EncoderValue {
+            text: EncoderText::default(),
+            format: HexFormat::default(),
+            bytes_per_row: 16,
+            group_size: HexFormat::default().default_group_size(),
+            uppercase: false,
+            error: None,
+        })
+    });
+    let format = value_context.read().format;
+    let bytes_per_row = value_context.read().bytes_per_row;
+    let group_size = value_context.read().group_size;
+    let uppercase = value_context.read().uppercase;
+    let error = value_context.read().error.clone();
+    let is_binary = value_context.read().text.is_binary();
+
+    rsx! {
+        div { class: "widget",
+            div { class: "widget-params",
+                SelectForm::<HexFormat> {
+                    label: "Format",
+                    oninput: move |format: HexFormat| {
+                        value_context
+                            .with_mut(|value| {
+                                value.format = format;
+                                value.group_size = format.default_group_size();
+                                value.refresh();
+                            });
+                    },
+                    value: format,
+                }
+                NumberInput::<u8> {
+                    label: "Bytes per Row",
+                    value: bytes_per_row,
+                    onchange: move |bytes_per_row| {
+                        value_context
+                            .with_mut(|value| {
+                                value.bytes_per_row = bytes_per_row;
+                                value.refresh();
+                            });
+                    },
+                }
+                NumberInput::<u8> {
+                    label: "Group Size",
+                    value: group_size,
+                    onchange: move |group_size| {
+                        value_context
+                            .with_mut(|value| {
+                                value.group_size = group_size;
+                                value.refresh();
+                            });
+                    },
+                }
+            }
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Uppercase",
+                    checked: uppercase,
+                    oninput: move |is_enabled| {
+                        value_context
+                            .with_mut(|value| {
+                                value.uppercase = is_enabled;
+                                value.refresh();
+                            });
+                    },
+                }
+            }
+            FileInput {
+                label: "Encode File",
+                onload: move |file: LoadedFile| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(file.bytes);
+                            value.encode();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary,
+                encoded_label: "Hex",
+                encoded_class: "hex-dump",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.encode();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary,
+                encoded_label: "Hex",
+                encoded_class: "hex-dump",
+                oninput: move |input: String| value_context.with_mut(|value| value.decode(input)),
+            }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            DecodedBytesView { bytes: value_context.read().text.decoded_bytes.clone() }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EncoderValue {
+    text: EncoderText,
+    format: HexFormat,
+    bytes_per_row: u8,
+    group_size: u8,
+    uppercase: bool,
+    error: Option<String>,
+}
+
+impl EncoderValue {
+    fn encode(&mut self) {
+        self.text.encoded_value = self.format.encode(
+            &self.text.decoded_bytes,
+            usize::from(self.bytes_per_row),
+            usize::from(self.group_size),
+            self.uppercase,
+        );
+        self.error = None;
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn decode(&mut self, input: String) {
+        match decode_hex(&input) {
+            Ok(bytes) => {
+                self.text.set_decoded_bytes(bytes);
+                self.error = None;
+            }
+            Err(err) => {
+                self.text.set_decoded_bytes(Vec::new());
+                self.error = Some(err);
+            }
+        }
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Re-encodes the text or re-decodes the hex input, whichever was typed last, after an
+    /// option changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.encode(),
+            Direction::Decode => self.decode(self.text.encoded_value.clone()),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum HexFormat {
+    #[strum(to_string = "Plain (48656c6c6f)")]
+    Plain,
+    #[default]
+    #[strum(to_string = "Spaced (48 65 6c)")]
+    Spaced,
+    #[strum(to_string = "Prefixed (0x48 0x65)")]
+    Prefixed,
+    #[strum(to_string = "Escaped (\\x48\\x65)")]
+    Escaped,
+    #[strum(to_string = "C Array")]
+    CArray,
+    #[strum(to_string = "Rust Array")]
+    RustArray,
+    #[strum(to_string = "xxd")]
+    Xxd,
+    #[strum(to_string = "hexdump -C")]
+    HexdumpC,
+}
+
+impl SelectFormEnum for HexFormat {}
+
+impl From<HexFormat> for String {
+    fn from(format: HexFormat) -> Self {
+        format.to_string()
+    }
+}
+
+impl HexFormat {
+    /// The grouping used by the tool each dump format imitates.
+    fn default_group_size(&self) -> u8 {
+        match self {
+            Self::Xxd => 2,
+            Self::HexdumpC => 8,
+            _ => 1,
+        }
+    }
+
+    /// Formats the bytes, starting a new line every `bytes_per_row` bytes (0 keeps one line).
+    fn encode(
+        &self,
+        bytes: &[u8],
+        bytes_per_row: usize,
+        group_size: usize,
+        uppercase: bool,
+    ) -> String {
+        let hex = |byte: &u8| match uppercase {
+            true => format!("{byte:02X}"),
+            false => format!("{byte:02x}"),
+        };
+        let row_length = match bytes_per_row {
+            0 => bytes.len().max(1),
+            _ => bytes_per_row,
+        };
+        let rows = |separator: &str, format_row: &dyn Fn(&[u8]) -> String| {
+            bytes
+                .chunks(row_length)
+                .map(format_row)
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        let array_rows = || {
+            rows(",\n", &|row| {
+                let row: Vec<String> = row.iter().map(|byte| format!("0x{}", hex(byte))).collect();
+                format!("    {}", row.join(", "))
+            })
+        };
+        match self {
+            Self::Plain => rows("\n", &|row| row.iter().map(hex).collect()),
+            Self::Spaced => rows("\n", &|row| {
+                row.chunks(group_size.max(1))
+                    .map(|group| group.iter().map(hex).collect::<String>())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }),
+            Self::Prefixed => rows("\n", &|row| {
+                let row: Vec<String> = row.iter().map(|byte| format!("0x{}", hex(byte))).collect();
+                row.join(" ")
+            }),
+            Self::Escaped => rows("\n", &|row| {
+                row.iter().map(|byte| format!("\\x{}", hex(byte))).collect()
+            }),
+            Self::CArray => format!(
+                "unsigned char data[{}] = {{\n{}\n}};",
+                bytes.len(),
+                array_rows()
+            ),
+            Self::RustArray => format!(
+                "const DATA: [u8; {}] = [\n{}\n];",
+                bytes.len(),
+                array_rows()
+            ),
+            Self::Xxd => xxd(bytes, row_length, group_size, uppercase),
+            Self::HexdumpC => hex_dump_with(bytes, row_length, group_size, uppercase),
+        }
+    }
+}
+
+/// Formats bytes like `xxd -c <bytes_per_row> -g <group_size>`.
+fn xxd(bytes: &[u8], bytes_per_row: usize, group_size: usize, uppercase: bool) -> String {
+    let group_size = match group_size {
+        0 => bytes_per_row,
+        _ => group_size,
+    };
+    let format_hex = |row: &[u8]| {
+        row.chunks(group_size)
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|byte| match uppercase {
+                        true => format!("{byte:02X}"),
+                        false => format!("{byte:02x}"),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let hex_width = format_hex(&vec![0; bytes_per_row]).len();
+    bytes
+        .chunks(bytes_per_row)
+        .enumerate()
+        .map(|(row, chunk)| {
+            format!(
+                "{:08x}: {:hex_width$}  {}",
+                row * bytes_per_row,
+                format_hex(chunk),
+                ascii_gutter(chunk)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Decodes any of the supported formats, recognising `xxd` and `hexdump -C` dumps by their
+/// offsets.
+pub(crate) fn decode_hex(input: &str) -> Result<Vec<u8>, String> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    // Every line of a dump starts with an offset, except that `*` marks repeated rows and
+    // hexdump ends with a line holding just the total length.
+    let is_offset =
+        |offset: &str| offset.len() >= 8 && offset.chars().all(|digit| digit.is_ascii_hexdigit());
+    let is_dump = |separator: &str| {
+        lines.iter().any(|line| line.contains(separator))
+            && lines.iter().enumerate().all(|(index, line)| {
+                *line == "*"
+                    || match line.split_once(separator) {
+                        Some((offset, _)) => is_offset(offset),
+                        None => index == lines.len() - 1 && is_offset(line),
+                    }
+            })
+    };
+    if is_dump(":") {
+        return decode_dump(&lines, ":", |hex| {
+            hex.split("  ").next().unwrap_or_default()
+        });
+    }
+    if is_dump("  ") {
+        return decode_dump(&lines, "  ", |hex| {
+            hex.split('|').next().unwrap_or_default()
+        });
+    }
+
+    if input.contains("0x") || input.contains("0X") {
+        // Array literals: every 0x-prefixed number is one byte, or several for longer numbers.
+        // A C or Rust declaration before the `=` is skipped.
+        let elements = input
+            .rsplit_once('=')
+            .map_or(input, |(_, elements)| elements);
+        let mut bytes = Vec::new();
+        for token in elements
+            .split(|character: char| !character.is_ascii_alphanumeric())
+            .filter(|token| !token.is_empty())
+        {
+            let Some(digits) = token.strip_prefix("0x").or(token.strip_prefix("0X")) else {
+                return Err(format!("Invalid array element {token}"));
+            };
+            match digits.len() {
+                1 | 2 => bytes.push(
+                    u8::from_str_radix(digits, 16).map_err(|_| format!("Invalid byte {token}"))?,
+                ),
+                _ => bytes.extend(decode_hex_digits(digits)?),
+            }
+        }
+        return Ok(bytes);
+    }
+    if input.contains("\\x") {
+        let (before, escapes) = input.split_once("\\x").unwrap_or_default();
+        if !before.trim().is_empty() {
+            return Err(format!(
+                "Unexpected text {} before the first escape",
+                before.trim()
+            ));
+        }
+        // Each escape is exactly two hex digits, optionally followed by line breaks.
+        return escapes
+            .split("\\x")
+            .map(|escape| {
+                let digits = escape.trim_end();
+                match digits.len() == 2 && digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+                    true => Ok(u8::from_str_radix(digits, 16).unwrap_or_default()),
+                    false => Err(format!("Invalid escape \\x{digits}")),
+                }
+            })
+            .collect();
+    }
+    let digits: String = input
+        .chars()
+        .filter(|character| !character.is_whitespace() && !":-,".contains(*character))
+        .collect();
+    decode_hex_digits(&digits)
+}
+
+fn decode_hex_digits(digits: &str) -> Result<Vec<u8>, String> {
+    if let Some(invalid) = digits.chars().find(|digit| !digit.is_ascii_hexdigit()) {
+        return Err(format!("Invalid hex digit '{invalid}'"));
+    }
+    if !digits.len().is_multiple_of(2) {
+        return Err("Hex string has an odd number of digits".to_string());
+    }
+    Ok((0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap_or_default())
+        .collect())
+}
+
+/// Decodes dump lines of `offset<separator>hex...`, expanding the `*` lines that stand for
+/// repeats of the previous row.
+fn decode_dump(
+    lines: &[&str],
+    separator: &str,
+    hex_column: impl Fn(&str) -> &str,
+) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    let mut previous_row: Vec<u8> = Vec::new();
+    let mut repeat = false;
+    for line in lines {
+        if *line == "*" {
+            repeat = true;
+            continue;
+        }
+        let (offset, hex) = line.split_once(separator).unwrap_or((line, ""));
+        let offset =
+            usize::from_str_radix(offset, 16).map_err(|_| format!("Invalid offset {offset}"))?;
+        if repeat && !previous_row.is_empty() {
+            if offset > MAX_DUMP_SIZE {
+                return Err(format!(
+                    "Repeated rows expand to more than {} MiB",
+                    MAX_DUMP_SIZE / 1024 / 1024
+                ));
+            }
+            while bytes.len() < offset {
+                bytes.extend_from_slice(&previous_row);
+            }
+            bytes.truncate(offset);
+            repeat = false;
+        }
+        if offset != bytes.len() {
+            return Err(format!(
+                "Offset {offset:08x} does not follow the previous row"
+            ));
+        }
+        let digits: String = hex_column(hex.trim_start())
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect();
+        previous_row = decode_hex_digits(&digits)?;
+        bytes.extend_from_slice(&previous_row);
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,299 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaFileCode;
+use entities::{Codepoints, ENTITIES};
+use std::{collections::HashMap, sync::LazyLock};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{SelectForm, SelectFormEnum};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "HTML Entity Encoder / Decoder",
+    short_title: "HTML Entities",
+    description: "Escape and unescape HTML and XML entities",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaFileCode> = WidgetIcon { icon: FaFileCode };
+
+/// Characters decoded from each HTML5 entity, keyed by the entity including its leading `&`.
+static ENTITY_CHARACTERS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    ENTITIES
+        .iter()
+        .map(|entity| (entity.entity, entity.characters))
+        .collect()
+});
+
+/// The preferred entity name for each character that has one: lowercase names are preferred
+/// over capitalized aliases, then shorter names over longer ones.
+static CHARACTER_ENTITIES: LazyLock<HashMap<char, &'static str>> = LazyLock::new(|| {
+    let mut names: HashMap<char, &'static str> = HashMap::new();
+    for entity in ENTITIES
+        .iter()
+        .filter(|entity| entity.entity.ends_with(';'))
+    {
+        let Codepoints::Single(codepoint) = entity.codepoints else {
+            continue;
+        };
+        let Some(character) = char::from_u32(codepoint) else {
+            continue;
+        };
+        let rank = |name: &str| {
+            (
+                !name[1..].starts_with(|first: char| first.is_ascii_lowercase()),
+                name.len(),
+            )
+        };
+        names
+            .entry(character)
+            .and_modify(|name| {
+                if rank(entity.entity) < rank(name) {
+                    *name = entity.entity;
+                }
+            })
+            .or_insert(entity.entity);
+    }
+    names
+});
+
+/// The longest entity name in the HTML5 table is `&CounterClockwiseContourIntegral;`.
+const MAX_ENTITY_NAME_LENGTH: usize = 32;
+
+pub fn HtmlEntityEncoder() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(EncoderValue {
+            text: EncoderText::default(),
+            mode: EscapeMode::default(),
+            format: EntityFormat::default(),
+        })
+    });
+    let mode = value_context.read().mode;
+    let format = value_context.read().format;
+
+    rsx! {
+        div { class: "widget",
+            div { class: "widget-params",
+                SelectForm::<EscapeMode> {
+                    label: "Characters to Escape",
+                    oninput: move |mode: EscapeMode| {
+                        value_context
+                            .with_mut(|value| {
+                                value.mode = mode;
+                                value.refresh();
+                            });
+                    },
+                    value: mode,
+                }
+                SelectForm::<EntityFormat> {
+                    label: "Entity Format",
+                    oninput: move |format: EntityFormat| {
+                        value_context
+                            .with_mut(|value| {
+                                value.format = format;
+                                value.refresh();
+                            });
+                    },
+                    value: format,
+                }
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary: false,
+                encoded_label: "Escaped",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.encode();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary: false,
+                encoded_label: "Escaped",
+                oninput: move |input: String| value_context.with_mut(|value| value.decode(input)),
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EncoderValue {
+    text: EncoderText,
+    mode: EscapeMode,
+    format: EntityFormat,
+}
+
+impl EncoderValue {
+    fn encode(&mut self) {
+        self.text.encoded_value = escape(&self.text.decoded_value, self.mode, self.format);
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn decode(&mut self, input: String) {
+        self.text.set_decoded_bytes(unescape(&input).into_bytes());
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Re-escapes the text or re-decodes the escaped input, whichever was typed last, after
+    /// an option changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.encode(),
+            Direction::Decode => self.decode(self.text.encoded_value.clone()),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum EscapeMode {
+    #[default]
+    #[strum(to_string = "Minimal (<>&\"')")]
+    Minimal,
+    #[strum(to_string = "All non-ASCII")]
+    NonAscii,
+    #[strum(to_string = "Attribute-safe")]
+    AttributeSafe,
+}
+
+impl SelectFormEnum for EscapeMode {}
+
+impl From<EscapeMode> for String {
+    fn from(mode: EscapeMode) -> Self {
+        mode.to_string()
+    }
+}
+
+impl EscapeMode {
+    fn should_escape(&self, character: char) -> bool {
+        let minimal = matches!(character, '<' | '>' | '&' | '"' | '\'');
+        match self {
+            Self::Minimal => minimal,
+            Self::NonAscii => minimal || !character.is_ascii(),
+            // Everything but alphanumerics and a few inert punctuation characters, so the value
+            // cannot break out of an unquoted attribute either.
+            Self::AttributeSafe => {
+                character.is_ascii()
+                    && !character.is_ascii_alphanumeric()
+                    && !matches!(character, ',' | '.' | '-' | '_')
+            }
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum EntityFormat {
+    #[default]
+    Named,
+    Decimal,
+    Hexadecimal,
+}
+
+impl SelectFormEnum for EntityFormat {}
+
+impl From<EntityFormat> for String {
+    fn from(format: EntityFormat) -> Self {
+        format.to_string()
+    }
+}
+
+/// Escapes the characters selected by the mode. Named entities fall back to hexadecimal
+/// references for characters without a name.
+fn escape(input: &str, mode: EscapeMode, format: EntityFormat) -> String {
+    let mut escaped = String::new();
+    for character in input.chars() {
+        if !mode.should_escape(character) {
+            escaped.push(character);
+            continue;
+        }
+        match format {
+            EntityFormat::Named => match CHARACTER_ENTITIES.get(&character) {
+                Some(name) => escaped.push_str(name),
+                None => escaped.push_str(&format!("&#x{:X};", u32::from(character))),
+            },
+            EntityFormat::Decimal => escaped.push_str(&format!("&#{};", u32::from(character))),
+            EntityFormat::Hexadecimal => {
+                escaped.push_str(&format!("&#x{:X};", u32::from(character)))
+            }
+        }
+    }
+    escaped
+}
+
+/// Unescapes named and numeric character references, including the legacy named entities
+/// that may omit the trailing semicolon.
+fn unescape(input: &str) -> String {
+    let mut unescaped = String::new();
+    let mut rest = input;
+    while let Some(position) = rest.find('&') {
+        unescaped.push_str(&rest[..position]);
+        rest = &rest[position..];
+        match unescape_reference(rest) {
+            Some((characters, length)) => {
+                unescaped.push_str(&characters);
+                rest = &rest[length..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Decodes the character reference at the start of `input`, returning the characters and the
+/// length of the reference.
+fn unescape_reference(input: &str) -> Option<(String, usize)> {
+    if let Some(number) = input.strip_prefix("&#") {
+        let (digits, radix, prefix_length) = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 3),
+            None => (number, 10, 2),
+        };
+        let digit_count = digits
+            .chars()
+            .take_while(|character| character.is_digit(radix))
+            .count();
+        if digit_count == 0 {
+            return None;
+        }
+        let character = u32::from_str_radix(&digits[..digit_count], radix)
+            .ok()
+            .and_then(char::from_u32)
+            .filter(|character| *character != '\0')
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        let semicolon = usize::from(digits[digit_count..].starts_with(';'));
+        return Some((
+            character.to_string(),
+            prefix_length + digit_count + semicolon,
+        ));
+    }
+
+    let name_length = input[1..]
+        .chars()
+        .take(MAX_ENTITY_NAME_LENGTH)
+        .take_while(|character| character.is_ascii_alphanumeric())
+        .count();
+    if input[1 + name_length..].starts_with(';') {
+        let entity = &input[..name_length + 2];
+        if let Some(characters) = ENTITY_CHARACTERS.get(entity) {
+            return Some((characters.to_string(), entity.len()));
+        }
+    }
+    (2..=name_length + 1).rev().find_map(|length| {
+        ENTITY_CHARACTERS
+            .get(&input[..length])
+            .map(|characters| (characters.to_string(), length))
+    })
+}
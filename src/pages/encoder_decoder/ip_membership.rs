@@ -0,0 +1,292 @@
+#![allow(non_snake_case)]
+use std::{net::IpAddr, str::FromStr};
+
+use cidr::{IpCidr, IpInet};
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaListCheck;
+
+use crate::{
+    components::inputs::TextAreaForm,
+    pages::{WidgetEntry, WidgetIcon},
+};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "IP Membership Checker",
+    short_title: "IP Membership",
+    description: "Check which CIDRs contain an address and which CIDRs overlap",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaListCheck> = WidgetIcon { icon: FaListCheck };
+
+/// Special-purpose ranges from the IANA IPv4 and IPv6 special-purpose address registries.
+const SPECIAL_RANGES: &[(&str, &str)] = &[
+    ("0.0.0.0/8", "This network (RFC 791)"),
+    ("10.0.0.0/8", "Private (RFC 1918)"),
+    ("100.64.0.0/10", "Shared address space / CGNAT (RFC 6598)"),
+    ("127.0.0.0/8", "Loopback (RFC 1122)"),
+    ("169.254.0.0/16", "Link-local (RFC 3927)"),
+    ("172.16.0.0/12", "Private (RFC 1918)"),
+    ("192.0.0.0/24", "IETF protocol assignments (RFC 6890)"),
+    ("192.0.2.0/24", "Documentation TEST-NET-1 (RFC 5737)"),
+    ("192.88.99.0/24", "6to4 relay anycast (RFC 7526)"),
+    ("192.168.0.0/16", "Private (RFC 1918)"),
+    ("198.18.0.0/15", "Benchmarking (RFC 2544)"),
+    ("198.51.100.0/24", "Documentation TEST-NET-2 (RFC 5737)"),
+    ("203.0.113.0/24", "Documentation TEST-NET-3 (RFC 5737)"),
+    ("224.0.0.0/4", "Multicast (RFC 5771)"),
+    ("240.0.0.0/4", "Reserved (RFC 1112)"),
+    ("255.255.255.255/32", "Limited broadcast (RFC 919)"),
+    ("::/128", "Unspecified (RFC 4291)"),
+    ("::1/128", "Loopback (RFC 4291)"),
+    ("::ffff:0:0/96", "IPv4-mapped (RFC 4291)"),
+    ("64:ff9b::/96", "NAT64 well-known prefix (RFC 6052)"),
+    ("64:ff9b:1::/48", "Local-use NAT64 (RFC 8215)"),
+    ("100::/64", "Discard-only (RFC 6666)"),
+    ("2001::/32", "Teredo (RFC 4380)"),
+    ("2001:db8::/32", "Documentation (RFC 3849)"),
+    ("2002::/16", "6to4 (RFC 3056)"),
+    ("3fff::/20", "Documentation (RFC 9637)"),
+    ("fc00::/7", "Unique local (RFC 4193)"),
+    ("fe80::/10", "Link-local (RFC 4291)"),
+    ("ff00::/8", "Multicast (RFC 4291)"),
+];
+
+pub fn IpMembershipChecker() -> Element {
+    let mut cidrs_input = use_signal(|| {
+        "vpc-prod 10.0.0.0/16\nvpc-staging 10.1.0.0/16\nvpc-shared 10.0.128.0/20\ncgnat 100.64.0.0/10\nula fd12:3456:789a::/48"
+            .to_string()
+    });
+    let mut addresses_input = use_signal(|| {
+        "10.0.130.7\n10.1.4.20\n10.4.7.9\n100.100.1.1\nfd12:3456:789a:1::10\n8.8.8.8".to_string()
+    });
+
+    let (cidrs, cidr_errors) = parse_lines(&cidrs_input.read(), parse_named_cidr);
+    let (addresses, address_errors) = parse_lines(&addresses_input.read(), |line| {
+        IpAddr::from_str(line).map_err(|_| format!("\"{line}\" is not an IP address."))
+    });
+    let overlaps: Vec<(&NamedCidr, &NamedCidr)> = cidrs
+        .iter()
+        .enumerate()
+        .flat_map(|(index, a)| cidrs[index + 1..].iter().map(move |b| (a, b)))
+        .filter(|(a, b)| cidrs_overlap(&a.cidr, &b.cidr))
+        .collect();
+    let errors: Vec<String> = cidr_errors.into_iter().chain(address_errors).collect();
+
+    rsx! {
+        div { class: "widget ip-membership",
+            div { class: "widget-params",
+                TextAreaForm {
+                    label: "CIDRs (optionally preceded by a name)",
+                    value: "{cidrs_input}",
+                    oninput: move |event: Event<FormData>| cidrs_input.set(event.value()),
+                }
+                TextAreaForm {
+                    label: "Addresses",
+                    value: "{addresses_input}",
+                    oninput: move |event: Event<FormData>| addresses_input.set(event.value()),
+                }
+            }
+            for error in errors {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            div { class: "ip-table-wrapper",
+                table { class: "table table-sm ip-table",
+                    caption { "Addresses" }
+                    thead {
+                        tr {
+                            th { "Address" }
+                            th { "Classification" }
+                            th { "Contained In" }
+                        }
+                    }
+                    tbody {
+                        for (index , address) in addresses.iter().enumerate() {
+                            AddressRow { key: "{index}", address: *address, cidrs: cidrs.clone() }
+                        }
+                    }
+                }
+            }
+            div { class: "ip-table-wrapper",
+                table { class: "table table-sm ip-table",
+                    caption { "CIDRs" }
+                    thead {
+                        tr {
+                            th { "Name" }
+                            th { "CIDR" }
+                            th { "Classification" }
+                        }
+                    }
+                    tbody {
+                        for (index , named_cidr) in cidrs.iter().enumerate() {
+                            tr { key: "{index}",
+                                td { "{named_cidr.name}" }
+                                td { "{named_cidr.cidr:#}" }
+                                td { "{classify_cidr(&named_cidr.cidr)}" }
+                            }
+                        }
+                    }
+                }
+            }
+            if overlaps.is_empty() {
+                div { class: "alert alert-success m-0", "None of the CIDRs overlap." }
+            } else {
+                div { class: "ip-table-wrapper",
+                    table { class: "table table-sm ip-table",
+                        caption { "Overlaps" }
+                        thead {
+                            tr {
+                                th { "First" }
+                                th { "Second" }
+                                th { "Relation" }
+                            }
+                        }
+                        tbody {
+                            for (index , (a , b)) in overlaps.iter().enumerate() {
+                                tr { key: "{index}", class: "flagged",
+                                    td { "{a}" }
+                                    td { "{b}" }
+                                    td { "{overlap_relation(&a.cidr, &b.cidr)}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AddressRow(address: IpAddr, cidrs: Vec<NamedCidr>) -> Element {
+    let matches: Vec<String> = cidrs
+        .iter()
+        .filter(|named_cidr| named_cidr.cidr.contains(&address))
+        .map(NamedCidr::to_string)
+        .collect();
+    let matched = !matches.is_empty();
+
+    rsx! {
+        tr { class: if matched { "matched" },
+            td { "{address}" }
+            td { "{classify_address(&address)}" }
+            td {
+                if !matched {
+                    "None"
+                }
+                for named_cidr in matches {
+                    div { "{named_cidr}" }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct NamedCidr {
+    name: String,
+    cidr: IpCidr,
+}
+
+impl std::fmt::Display for NamedCidr {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.name.is_empty() {
+            true => write!(f, "{:#}", self.cidr),
+            false => write!(f, "{} ({:#})", self.name, self.cidr),
+        }
+    }
+}
+
+/// Parses `name cidr`, `name: cidr`, `name,cidr` or a bare CIDR; host bits are cleared.
+fn parse_named_cidr(line: &str) -> Result<NamedCidr, String> {
+    // IPv6 CIDRs contain colons themselves, so the CIDR is the last token after whitespace,
+    // commas or equals signs.
+    let (name, cidr) = match line
+        .rfind(|character: char| character.is_whitespace() || character == ',' || character == '=')
+    {
+        Some(position) => (
+            line[..position]
+                .trim()
+                .trim_end_matches([':', ',', '='])
+                .trim(),
+            &line[position + 1..],
+        ),
+        None => ("", line),
+    };
+    let cidr = IpInet::from_str(cidr)
+        .map(|inet| inet.network())
+        .map_err(|_| format!("\"{line}\" is not a CIDR."))?;
+    Ok(NamedCidr {
+        name: name.to_string(),
+        cidr,
+    })
+}
+
+/// Parses every non-empty line, ignoring `#` comments, and collects the errors separately.
+fn parse_lines<T>(input: &str, parse: impl Fn(&str) -> Result<T, String>) -> (Vec<T>, Vec<String>) {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for line in input
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+    {
+        match parse(line) {
+            Ok(value) => values.push(value),
+            Err(err) => errors.push(err),
+        }
+    }
+    (values, errors)
+}
+
+fn cidrs_overlap(a: &IpCidr, b: &IpCidr) -> bool {
+    a.contains(&b.first_address()) || b.contains(&a.first_address())
+}
+
+/// CIDRs either nest or are disjoint, so overlapping CIDRs always contain one another.
+fn overlap_relation(a: &IpCidr, b: &IpCidr) -> String {
+    match a.network_length().cmp(&b.network_length()) {
+        std::cmp::Ordering::Equal => "Identical".to_string(),
+        std::cmp::Ordering::Less => format!("First contains {b:#}"),
+        std::cmp::Ordering::Greater => format!("Second contains {a:#}"),
+    }
+}
+
+fn special_ranges() -> impl Iterator<Item = (IpCidr, &'static str)> {
+    SPECIAL_RANGES.iter().map(|(cidr, name)| {
+        (
+            IpCidr::from_str(cidr).expect("special ranges are valid CIDRs"),
+            *name,
+        )
+    })
+}
+
+/// Names the most specific special-purpose range containing the address.
+fn classify_address(address: &IpAddr) -> &'static str {
+    special_ranges()
+        .filter(|(cidr, _)| cidr.contains(address))
+        .max_by_key(|(cidr, _)| cidr.network_length())
+        .map_or("Global unicast", |(_, name)| name)
+}
+
+/// Classifies a whole CIDR, listing the special-purpose ranges it only partly covers.
+fn classify_cidr(cidr: &IpCidr) -> String {
+    let containing = special_ranges()
+        .filter(|(range, _)| {
+            range.contains(&cidr.first_address()) && range.network_length() <= cidr.network_length()
+        })
+        .max_by_key(|(range, _)| range.network_length());
+    if let Some((_, name)) = containing {
+        return name.to_string();
+    }
+    let mut included: Vec<&str> = Vec::new();
+    for (range, name) in special_ranges() {
+        if cidr.contains(&range.first_address()) && !included.contains(&name) {
+            included.push(name);
+        }
+    }
+    match included.is_empty() {
+        true => "Global unicast".to_string(),
+        false => format!("Mixed: global unicast and {}", included.join(", ")),
+    }
+}
@@ -0,0 +1,374 @@
+#![allow(non_snake_case)]
+use base64ct::{Base64UrlUnpadded, Encoding};
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaIdCard;
+use jsonwebtoken::{
+    crypto,
+    jwk::{Jwk, JwkSet},
+    Algorithm, AlgorithmFamily, DecodingKey, EncodingKey,
+};
+use serde_json::Value;
+use std::str::FromStr;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+use time::OffsetDateTime;
+use time_tz::OffsetDateTimeExt;
+
+use crate::components::inputs::{SelectForm, SelectFormEnum, SwitchInput, TextAreaForm, TextInput};
+use crate::pages::converter::date_converter::DcTimeZone;
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "JWT Decoder",
+    short_title: "JWT",
+    description: "Decode, verify and sign JSON Web Tokens",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaIdCard> = WidgetIcon { icon: FaIdCard };
+
+/// Registered claims holding NumericDate values, with their display labels.
+const DATE_CLAIMS: [(&str, &str); 3] = [
+    ("iat", "Issued At (iat)"),
+    ("nbf", "Not Before (nbf)"),
+    ("exp", "Expires (exp)"),
+];
+
+pub fn JwtDecoder() -> Element {
+    let mut token = use_signal(String::new);
+    let mut header_json = use_signal(String::new);
+    let mut payload_json = use_signal(String::new);
+    let mut secret_or_public_key = use_signal(String::new);
+    let mut private_key = use_signal(String::new);
+    let mut secret_base64 = use_signal(|| false);
+    let mut time_zone = use_signal(DcTimeZone::default);
+    let mut signing_error = use_signal(|| None::<String>);
+
+    let decoded = decode_token(&token.read());
+    let algorithm = algorithm_from_header(&header_json.read());
+    let verification = (!secret_or_public_key.read().trim().is_empty() && decoded.is_ok())
+        .then(|| verify_token(&token.read(), &secret_or_public_key.read(), secret_base64()));
+    let claim_dates = claim_dates(&payload_json.read());
+    let now = OffsetDateTime::now_utc();
+
+    rsx! {
+        div { class: "widget",
+            TextAreaForm {
+                label: "Token",
+                value: "{token}",
+                oninput: move |event: Event<FormData>| {
+                    let input_value = event.value();
+                    if let Ok((header, payload)) = decode_token(&input_value) {
+                        header_json.set(header);
+                        payload_json.set(payload);
+                    }
+                    token.set(input_value);
+                },
+            }
+            if let Err(err) = decoded {
+                if !token.read().trim().is_empty() {
+                    div { class: "alert alert-warning m-0", "{err}" }
+                }
+            }
+            TextAreaForm {
+                label: "Header",
+                value: "{header_json}",
+                oninput: move |event: Event<FormData>| header_json.set(event.value()),
+            }
+            TextAreaForm {
+                label: "Payload",
+                value: "{payload_json}",
+                oninput: move |event: Event<FormData>| payload_json.set(event.value()),
+            }
+            div { class: "widget-params",
+                SelectForm::<DcTimeZone> {
+                    label: "Time Zone",
+                    oninput: move |tz: DcTimeZone| time_zone.set(tz),
+                    value: time_zone(),
+                }
+                for (claim , label , timestamp) in claim_dates.iter().cloned() {
+                    TextInput {
+                        key: "{claim}",
+                        label,
+                        value: format_timestamp(timestamp, time_zone()),
+                        readonly: true,
+                    }
+                }
+            }
+            for (claim , _ , timestamp) in claim_dates.iter().cloned() {
+                if claim == "exp" && timestamp <= now.unix_timestamp() {
+                    div { class: "alert alert-warning m-0",
+                        "This token expired on {format_timestamp(timestamp, time_zone())}"
+                    }
+                }
+                if claim == "nbf" && timestamp > now.unix_timestamp() {
+                    div { class: "alert alert-warning m-0",
+                        "This token is not valid before {format_timestamp(timestamp, time_zone())}"
+                    }
+                }
+            }
+            TextAreaForm {
+                label: "Secret or Public Key (PEM or JWK)",
+                value: "{secret_or_public_key}",
+                oninput: move |event: Event<FormData>| secret_or_public_key.set(event.value()),
+            }
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Secret is Base64 encoded",
+                    checked: secret_base64(),
+                    oninput: move |is_enabled| secret_base64.set(is_enabled),
+                }
+            }
+            match verification {
+                Some(Ok(true)) => rsx! {
+                    div { class: "alert alert-success m-0", "Signature verified" }
+                },
+                Some(Ok(false)) => rsx! {
+                    div { class: "alert alert-warning m-0", "Invalid signature" }
+                },
+                Some(Err(err)) => rsx! {
+                    div { class: "alert alert-warning m-0", "{err}" }
+                },
+                None => rsx! {},
+            }
+            div { class: "widget-params",
+                SelectForm::<SigningAlgorithm> {
+                    label: "Signing Algorithm",
+                    oninput: move |algorithm: SigningAlgorithm| {
+                        let header = set_header_algorithm(&header_json.read(), algorithm);
+                        if let Ok(header) = header {
+                            header_json.set(header);
+                        }
+                    },
+                    value: algorithm.unwrap_or_default(),
+                }
+            }
+            if algorithm.is_some_and(|algorithm| algorithm.algorithm().family() != AlgorithmFamily::Hmac) {
+                TextAreaForm {
+                    label: "Private Key (PEM)",
+                    value: "{private_key}",
+                    oninput: move |event: Event<FormData>| private_key.set(event.value()),
+                }
+            }
+            div { class: "widget-buttons",
+                button {
+                    class: "btn btn-info",
+                    onclick: move |_| {
+                        let signed = sign_token(
+                            &header_json.read(),
+                            &payload_json.read(),
+                            &secret_or_public_key.read(),
+                            &private_key.read(),
+                            secret_base64(),
+                        );
+                        match signed {
+                            Ok(signed) => {
+                                token.set(signed);
+                                signing_error.set(None);
+                            }
+                            Err(err) => signing_error.set(Some(err)),
+                        }
+                    },
+                    "Sign Token"
+                }
+            }
+            if let Some(err) = signing_error() {
+                div { class: "alert alert-warning m-0", "{err}" }
+            }
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum SigningAlgorithm {
+    #[default]
+    HS256,
+    HS384,
+    HS512,
+    RS256,
+    RS384,
+    RS512,
+    PS256,
+    PS384,
+    PS512,
+    ES256,
+    ES384,
+    EdDSA,
+}
+
+impl SelectFormEnum for SigningAlgorithm {}
+
+impl From<SigningAlgorithm> for String {
+    fn from(algorithm: SigningAlgorithm) -> Self {
+        algorithm.to_string()
+    }
+}
+
+impl SigningAlgorithm {
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::from_str(self.into()).unwrap_or_default()
+    }
+}
+
+/// Splits a compact JWS and pretty-prints its header and payload.
+fn decode_token(token: &str) -> Result<(String, String), String> {
+    let segments: Vec<&str> = token.trim().split('.').collect();
+    let [header, payload, _signature] = segments[..] else {
+        return Err(format!(
+            "Expected 3 dot-separated segments, found {}",
+            segments.len()
+        ));
+    };
+    Ok((
+        decode_segment(header).map_err(|err| format!("Invalid header: {err}"))?,
+        decode_segment(payload).map_err(|err| format!("Invalid payload: {err}"))?,
+    ))
+}
+
+/// Decodes a Base64url segment, pretty-printing it when it is JSON.
+fn decode_segment(segment: &str) -> Result<String, String> {
+    let bytes = Base64UrlUnpadded::decode_vec(segment.trim_end_matches('='))
+        .map_err(|err| err.to_string())?;
+    match serde_json::from_slice::<Value>(&bytes) {
+        Ok(json) => serde_json::to_string_pretty(&json).map_err(|err| err.to_string()),
+        Err(_) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+    }
+}
+
+/// Re-serializes a JSON segment compactly and Base64url-encodes it.
+fn encode_segment(json: &str, name: &str) -> Result<String, String> {
+    let json: Value =
+        serde_json::from_str(json).map_err(|err| format!("Invalid {name} JSON: {err}"))?;
+    Ok(Base64UrlUnpadded::encode_string(
+        json.to_string().as_bytes(),
+    ))
+}
+
+fn algorithm_from_header(header: &str) -> Option<SigningAlgorithm> {
+    let header: Value = serde_json::from_str(header).ok()?;
+    SigningAlgorithm::from_str(header.get("alg")?.as_str()?).ok()
+}
+
+fn set_header_algorithm(header: &str, algorithm: SigningAlgorithm) -> Result<String, String> {
+    let mut header = match header.trim().is_empty() {
+        true => serde_json::json!({ "typ": "JWT" }),
+        false => serde_json::from_str(header).map_err(|err| err.to_string())?,
+    };
+    let Value::Object(fields) = &mut header else {
+        return Err("Header must be a JSON object".to_string());
+    };
+    fields.insert("alg".to_string(), Value::from(algorithm.to_string()));
+    serde_json::to_string_pretty(&header).map_err(|err| err.to_string())
+}
+
+/// Returns the NumericDate claims present in the payload, truncated to whole seconds.
+fn claim_dates(payload: &str) -> Vec<(&'static str, &'static str, i64)> {
+    let Ok(payload) = serde_json::from_str::<Value>(payload) else {
+        return Vec::new();
+    };
+    DATE_CLAIMS
+        .iter()
+        .filter_map(|(claim, label)| {
+            let value = payload.get(claim)?;
+            let timestamp = value
+                .as_i64()
+                .or_else(|| value.as_f64().map(|value| value as i64))?;
+            Some((*claim, *label, timestamp))
+        })
+        .collect()
+}
+
+fn format_timestamp(timestamp: i64, time_zone: DcTimeZone) -> String {
+    match OffsetDateTime::from_unix_timestamp(timestamp) {
+        Ok(datetime) => datetime.to_timezone(time_zone.inner()).to_string(),
+        Err(_) => format!("{timestamp} (out of range)"),
+    }
+}
+
+fn verify_token(token: &str, key: &str, secret_base64: bool) -> Result<bool, String> {
+    let token = token.trim();
+    let (message, signature) = token.rsplit_once('.').ok_or("Missing signature")?;
+    let header: Value = serde_json::from_str(&decode_segment(
+        message.split('.').next().unwrap_or_default(),
+    )?)
+    .map_err(|err| format!("Invalid header: {err}"))?;
+    let algorithm = match header.get("alg").and_then(Value::as_str) {
+        Some("none") => return Err("Unsecured token (alg \"none\") has no signature".to_string()),
+        Some(algorithm) => Algorithm::from_str(algorithm)
+            .map_err(|_| format!("Unsupported algorithm \"{algorithm}\""))?,
+        None => return Err("Header has no \"alg\"".to_string()),
+    };
+    let kid = header.get("kid").and_then(Value::as_str);
+    let key = decoding_key(key, algorithm, kid, secret_base64)?;
+    crypto::verify(signature, message.as_bytes(), &key, algorithm).map_err(|err| err.to_string())
+}
+
+/// Parses a shared secret, PEM public key, JWK or JWK set for the algorithm's family.
+fn decoding_key(
+    key: &str,
+    algorithm: Algorithm,
+    kid: Option<&str>,
+    secret_base64: bool,
+) -> Result<DecodingKey, String> {
+    let trimmed = key.trim();
+    let key = if trimmed.starts_with('{') {
+        let jwk = match serde_json::from_str::<JwkSet>(trimmed) {
+            Ok(jwk_set) => match (kid, jwk_set.keys.as_slice()) {
+                (Some(kid), _) => jwk_set
+                    .find(kid)
+                    .cloned()
+                    .ok_or(format!("JWK set has no key with kid \"{kid}\""))?,
+                (None, [jwk]) => jwk.clone(),
+                (None, []) => return Err("JWK set has no keys".to_string()),
+                (None, keys) => {
+                    return Err(format!(
+                        "Header has no \"kid\" to choose between the {} keys in the JWK set",
+                        keys.len()
+                    ))
+                }
+            },
+            Err(_) => serde_json::from_str::<Jwk>(trimmed).map_err(|err| err.to_string())?,
+        };
+        DecodingKey::from_jwk(&jwk)
+    } else {
+        match algorithm.family() {
+            AlgorithmFamily::Hmac if secret_base64 => DecodingKey::from_base64_secret(trimmed),
+            AlgorithmFamily::Hmac => Ok(DecodingKey::from_secret(key.as_bytes())),
+            AlgorithmFamily::Rsa => DecodingKey::from_rsa_pem(trimmed.as_bytes()),
+            AlgorithmFamily::Ec => DecodingKey::from_ec_pem(trimmed.as_bytes()),
+            AlgorithmFamily::Ed => DecodingKey::from_ed_pem(trimmed.as_bytes()),
+        }
+    };
+    key.map_err(|err| format!("Invalid key: {err}"))
+}
+
+/// Signs the edited header and payload with the header's algorithm. HMAC algorithms use the
+/// shared secret, the others the PEM private key.
+fn sign_token(
+    header: &str,
+    payload: &str,
+    secret: &str,
+    private_key: &str,
+    secret_base64: bool,
+) -> Result<String, String> {
+    let algorithm = algorithm_from_header(header)
+        .ok_or("Header needs a supported \"alg\"")?
+        .algorithm();
+    let key = match algorithm.family() {
+        AlgorithmFamily::Hmac if secret_base64 => EncodingKey::from_base64_secret(secret.trim()),
+        AlgorithmFamily::Hmac => Ok(EncodingKey::from_secret(secret.as_bytes())),
+        AlgorithmFamily::Rsa => EncodingKey::from_rsa_pem(private_key.trim().as_bytes()),
+        AlgorithmFamily::Ec => EncodingKey::from_ec_pem(private_key.trim().as_bytes()),
+        AlgorithmFamily::Ed => EncodingKey::from_ed_pem(private_key.trim().as_bytes()),
+    }
+    .map_err(|err| format!("Invalid key: {err}"))?;
+    let message = format!(
+        "{}.{}",
+        encode_segment(header, "header")?,
+        encode_segment(payload, "payload")?
+    );
+    let signature =
+        crypto::sign(message.as_bytes(), &key, algorithm).map_err(|err| err.to_string())?;
+    Ok(format!("{message}.{signature}"))
+}
@@ -0,0 +1,206 @@
+#![allow(non_snake_case)]
+use std::{io::Read, net::Ipv6Addr, sync::LazyLock};
+
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaEthernet;
+use flate2::read::ZlibDecoder;
+use rand::Rng;
+
+use crate::{
+    components::inputs::{SwitchInput, TextInput},
+    pages::{
+        encoder_decoder::cidr_decoder::{eui64_interface_id, parse_mac},
+        WidgetEntry, WidgetIcon,
+    },
+};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "MAC Address Tool",
+    short_title: "MAC Address",
+    description: "Normalize MAC addresses, inspect their flag bits and look up the vendor",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaEthernet> = WidgetIcon { icon: FaEthernet };
+
+/// The IEEE MA-L registry, generated by `scripts/generate_oui_registry.py`: a big-endian entry
+/// count, then 6-byte entries sorted by OUI that pair the OUI with the offset of its
+/// newline-terminated organization name in the names that follow. It is embedded
+/// zlib-compressed and inflated on the first lookup.
+static OUI_REGISTRY: LazyLock<Vec<u8>> = LazyLock::new(|| {
+    let mut registry = Vec::new();
+    ZlibDecoder::new(&include_bytes!("oui_registry.bin.zlib")[..])
+        .read_to_end(&mut registry)
+        .expect("the embedded OUI registry is valid zlib");
+    registry
+});
+
+pub fn MacAddressTool() -> Element {
+    let mut mac_input = use_signal(|| "00:1B:21:3C:4D:5E".to_string());
+    let mut uppercase = use_signal(|| true);
+
+    let mac = parse_mac(&mac_input.read());
+
+    rsx! {
+        div { class: "widget",
+            TextInput {
+                label: "MAC Address",
+                value: "{mac_input}",
+                oninput: move |event: Event<FormData>| mac_input.set(event.value()),
+            }
+            div { class: "widget-switches",
+                SwitchInput {
+                    label: "Uppercase",
+                    checked: uppercase(),
+                    oninput: move |is_enabled| uppercase.set(is_enabled),
+                }
+            }
+            div { class: "widget-buttons",
+                button {
+                    class: "btn btn-info",
+                    onclick: move |_| mac_input.set(MacFormat::Colon.format_bytes(&random_local_mac(), uppercase())),
+                    "Random Locally Administered MAC"
+                }
+            }
+            if let Some(mac) = mac {
+                div { class: "widget-params",
+                    for format in [MacFormat::Colon, MacFormat::Dash, MacFormat::CiscoDot, MacFormat::Bare] {
+                        TextInput {
+                            label: format.label(),
+                            value: format.format_bytes(&mac, uppercase()),
+                            readonly: true,
+                        }
+                    }
+                }
+                div { class: "widget-params",
+                    TextInput {
+                        label: "Transmission (I/G bit)",
+                        value: transmission_type(mac),
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Administration (U/L bit)",
+                        value: if is_locally_administered(mac) { "Locally administered" } else { "Universally administered" },
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "OUI",
+                        value: MacFormat::Colon.format_bytes(&mac[..3], uppercase()),
+                        readonly: true,
+                    }
+                    TextInput { label: "Vendor", value: vendor(mac), readonly: true }
+                }
+                div { class: "widget-params",
+                    TextInput {
+                        label: "EUI-64",
+                        value: MacFormat::Colon.format_bytes(&eui64(mac), uppercase()),
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Modified EUI-64 Interface ID",
+                        value: MacFormat::Colon.format_bytes(&eui64_interface_id(mac), uppercase()),
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "IPv6 Link-Local Address",
+                        value: "{link_local_address(mac)}",
+                        readonly: true,
+                    }
+                }
+            } else {
+                div { class: "alert alert-warning m-0", "The provided MAC address is invalid." }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum MacFormat {
+    Colon,
+    Dash,
+    CiscoDot,
+    Bare,
+}
+
+impl MacFormat {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Colon => "Colon",
+            Self::Dash => "Dash",
+            Self::CiscoDot => "Cisco Dot",
+            Self::Bare => "Bare",
+        }
+    }
+
+    fn format_bytes(&self, bytes: &[u8], uppercase: bool) -> String {
+        let hex: Vec<String> = bytes
+            .iter()
+            .map(|byte| match uppercase {
+                true => format!("{byte:02X}"),
+                false => format!("{byte:02x}"),
+            })
+            .collect();
+        match self {
+            Self::Colon => hex.join(":"),
+            Self::Dash => hex.join("-"),
+            Self::CiscoDot => hex
+                .chunks(2)
+                .map(|pair| pair.concat())
+                .collect::<Vec<_>>()
+                .join("."),
+            Self::Bare => hex.concat(),
+        }
+    }
+}
+
+fn transmission_type(mac: [u8; 6]) -> &'static str {
+    match mac {
+        [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF] => "Broadcast",
+        [first, ..] if first & 0x01 != 0 => "Multicast",
+        _ => "Unicast",
+    }
+}
+
+fn is_locally_administered(mac: [u8; 6]) -> bool {
+    mac[0] & 0x02 != 0
+}
+
+fn vendor(mac: [u8; 6]) -> String {
+    match registered_organization([mac[0], mac[1], mac[2]]) {
+        Some(organization) => organization.to_string(),
+        None if is_locally_administered(mac) => "None (locally administered)".to_string(),
+        None => "Unknown".to_string(),
+    }
+}
+
+/// Looks the OUI up in the embedded registry.
+fn registered_organization(oui: [u8; 3]) -> Option<&'static str> {
+    let (count, table) = OUI_REGISTRY.split_first_chunk::<4>()?;
+    let (entries, names) = table.split_at(u32::from_be_bytes(*count) as usize * 6);
+    let (entries, _) = entries.as_chunks::<6>();
+    let index = entries
+        .binary_search_by_key(&oui, |entry| [entry[0], entry[1], entry[2]])
+        .ok()?;
+    let [_, _, _, offset @ ..] = entries[index];
+    let name = &names[u32::from_be_bytes([0, offset[0], offset[1], offset[2]]) as usize..];
+    let end = name.iter().position(|byte| *byte == b'\n')?;
+    std::str::from_utf8(&name[..end]).ok()
+}
+
+/// The unmodified EUI-64 with `FF:FE` inserted between the OUI and the device identifier.
+fn eui64(mac: [u8; 6]) -> [u8; 8] {
+    [mac[0], mac[1], mac[2], 0xFF, 0xFE, mac[3], mac[4], mac[5]]
+}
+
+fn link_local_address(mac: [u8; 6]) -> Ipv6Addr {
+    let interface_id = u64::from_be_bytes(eui64_interface_id(mac));
+    Ipv6Addr::from(0xFE80_u128 << 112 | u128::from(interface_id))
+}
+
+/// Generates a random unicast MAC with the locally administered bit set, as used for
+/// virtual machines and containers.
+fn random_local_mac() -> [u8; 6] {
+    let mut mac: [u8; 6] = rand::thread_rng().gen();
+    mac[0] = (mac[0] & 0xFC) | 0x02;
+    mac
+}
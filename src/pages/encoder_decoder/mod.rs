@@ -4,7 +4,20 @@ use dioxus_free_icons::Icon;
 use strum_macros::EnumIter;
 
 pub mod base64_encoder;
+pub mod base_n_encoder;
 pub mod cidr_decoder;
+pub mod compression;
+pub mod hex_encoder;
+pub mod html_entity_encoder;
+pub mod ip_membership;
+pub mod jwt_decoder;
+pub mod mac_address;
+pub mod qr_code_decoder;
+pub mod string_escaper;
+pub mod subnet_planner;
+pub mod unicode_inspector;
+pub mod url_encoder;
+pub mod url_parser;
 
 use crate::pages::{CategoryEntry, Route, WidgetEntry, WidgetRoute};
 
@@ -18,7 +31,20 @@ pub static CATEGORY_ENTRY: CategoryEntry = CategoryEntry {
     },
 };
 use base64_encoder::Base64Encoder;
+use base_n_encoder::BaseNEncoder;
 use cidr_decoder::CidrDecoder;
+use compression::Compressor;
+use hex_encoder::HexEncoder;
+use html_entity_encoder::HtmlEntityEncoder;
+use ip_membership::IpMembershipChecker;
+use jwt_decoder::JwtDecoder;
+use mac_address::MacAddressTool;
+use qr_code_decoder::QrCodeDecoder;
+use string_escaper::StringEscaper;
+use subnet_planner::SubnetPlanner;
+use unicode_inspector::UnicodeInspector;
+use url_encoder::UrlEncoder;
+use url_parser::UrlParser;
 
 #[derive(Clone, Debug, EnumIter, PartialEq, Routable)]
 pub enum EncoderDecoderRoute {
@@ -26,8 +52,34 @@ pub enum EncoderDecoderRoute {
     Index {},
     #[route("/base64")]
     Base64Encoder {},
+    #[route("/base-n")]
+    BaseNEncoder {},
     #[route("/cidr")]
     CidrDecoder {},
+    #[route("/compression")]
+    Compressor {},
+    #[route("/hex")]
+    HexEncoder {},
+    #[route("/html-entities")]
+    HtmlEntityEncoder {},
+    #[route("/ip-membership")]
+    IpMembershipChecker {},
+    #[route("/jwt")]
+    JwtDecoder {},
+    #[route("/mac-address")]
+    MacAddressTool {},
+    #[route("/qr-code")]
+    QrCodeDecoder {},
+    #[route("/string-escape")]
+    StringEscaper {},
+    #[route("/subnet-planner")]
+    SubnetPlanner {},
+    #[route("/unicode")]
+    UnicodeInspector {},
+    #[route("/url")]
+    UrlEncoder {},
+    #[route("/url-parser")]
+    UrlParser {},
 }
 
 fn Index() -> Element {
@@ -53,7 +105,20 @@ impl WidgetRoute for EncoderDecoderRoute {
     fn get_widget_entry(&self) -> Option<&'static WidgetEntry> {
         match self {
             Self::Base64Encoder { .. } => Some(&base64_encoder::WIDGET_ENTRY),
+            Self::BaseNEncoder { .. } => Some(&base_n_encoder::WIDGET_ENTRY),
             Self::CidrDecoder { .. } => Some(&cidr_decoder::WIDGET_ENTRY),
+            Self::Compressor { .. } => Some(&compression::WIDGET_ENTRY),
+            Self::HexEncoder { .. } => Some(&hex_encoder::WIDGET_ENTRY),
+            Self::HtmlEntityEncoder { .. } => Some(&html_entity_encoder::WIDGET_ENTRY),
+            Self::IpMembershipChecker { .. } => Some(&ip_membership::WIDGET_ENTRY),
+            Self::JwtDecoder { .. } => Some(&jwt_decoder::WIDGET_ENTRY),
+            Self::MacAddressTool { .. } => Some(&mac_address::WIDGET_ENTRY),
+            Self::QrCodeDecoder { .. } => Some(&qr_code_decoder::WIDGET_ENTRY),
+            Self::StringEscaper { .. } => Some(&string_escaper::WIDGET_ENTRY),
+            Self::SubnetPlanner { .. } => Some(&subnet_planner::WIDGET_ENTRY),
+            Self::UnicodeInspector { .. } => Some(&unicode_inspector::WIDGET_ENTRY),
+            Self::UrlEncoder { .. } => Some(&url_encoder::WIDGET_ENTRY),
+            Self::UrlParser { .. } => Some(&url_parser::WIDGET_ENTRY),
             _ => None,
         }
     }
@@ -0,0 +1,1637 @@
+#![allow(non_snake_case)]
+use base64ct::{Base64, Encoding};
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaCamera;
+use image::GrayImage;
+use strum_macros::Display;
+
+use crate::{
+    components::inputs::{FileInput, LoadedFile, TextAreaForm, TextInput},
+    pages::{generator::qr_code_generator::Ecc, WidgetEntry, WidgetIcon},
+    utils::{
+        barcode::{
+            ean_check_digit, CODE128_PATTERNS, CODE39_ALPHABET, CODE39_PATTERNS,
+            EAN_DIGIT_PATTERNS, EAN_FIRST_DIGIT_PARITY,
+        },
+        detect_file_signature,
+    },
+};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "QR Code / Barcode Decoder",
+    short_title: "QR Decoder",
+    description: "Decode QR codes and barcodes from images",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaCamera> = WidgetIcon { icon: FaCamera };
+
+pub fn QrCodeDecoder() -> Element {
+    let mut image_state = use_signal(|| None::<LoadedFile>);
+    let mut decode_state = use_signal(|| None::<Result<Vec<DecodedSymbol>, String>>);
+
+    let image_src = image_state.with(|image| {
+        image.as_ref().map(|image| {
+            format!(
+                "data:{};base64,{}",
+                detect_file_signature(&image.bytes)
+                    .map_or("image/jpeg", |signature| signature.mime_type),
+                Base64::encode_string(&image.bytes)
+            )
+        })
+    });
+
+    let results = match &*decode_state.read() {
+        Some(Ok(symbols)) if symbols.is_empty() => rsx! {
+            div { class: "alert alert-warning m-0", "No QR code or barcode was found in the image." }
+        },
+        Some(Ok(symbols)) => rsx! {
+            for symbol in symbols.iter() {
+                DecodedSymbolView { symbol: symbol.clone() }
+            }
+        },
+        Some(Err(err)) => rsx! {
+            div { class: "alert alert-warning m-0", "The provided file could not be read as an image: {err}" }
+        },
+        None => rsx! {},
+    };
+
+    rsx! {
+        div { class: "widget qr-code-decoder",
+            FileInput {
+                label: "Image (PNG, JPEG, GIF)",
+                accept: "image/png,image/jpeg,image/gif",
+                onload: move |file: LoadedFile| {
+                    let result = decode_image(&file.bytes).map_err(|err| err.to_string());
+                    decode_state.set(Some(result));
+                    image_state.set(Some(file));
+                },
+            }
+            {results}
+            if let Some(image_src) = image_src {
+                img { class: "decoded-image", src: "{image_src}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn DecodedSymbolView(symbol: DecodedSymbol) -> Element {
+    rsx! {
+        div { class: "decoded-symbol",
+            div { class: "widget-params",
+                TextInput {
+                    label: "Symbology",
+                    value: "{symbol.symbology}",
+                    readonly: true,
+                }
+                if let Some(qr_info) = symbol.qr_info {
+                    TextInput {
+                        label: "Version",
+                        value: "{qr_info.version} ({qr_info.size()}x{qr_info.size()})",
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Error Correction Level",
+                        value: "{qr_info.ecc}",
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Mask",
+                        value: "{qr_info.mask}",
+                        readonly: true,
+                    }
+                }
+            }
+            TextAreaForm {
+                label: "Payload",
+                value: "{symbol.payload}",
+                readonly: true,
+            }
+        }
+    }
+}
+
+fn decode_image(bytes: &[u8]) -> Result<Vec<DecodedSymbol>, image::ImageError> {
+    let image = image::load_from_memory(bytes)?.to_luma8();
+    let mut symbols = find_qr_codes(&image);
+    for symbol in find_linear_barcodes(&image) {
+        if !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    Ok(symbols)
+}
+
+#[derive(Clone, PartialEq)]
+struct DecodedSymbol {
+    symbology: Symbology,
+    payload: String,
+    qr_info: Option<QrInfo>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct QrInfo {
+    version: u8,
+    ecc: Ecc,
+    mask: u8,
+}
+
+impl QrInfo {
+    fn size(&self) -> usize {
+        qr_size(self.version)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+enum Symbology {
+    #[strum(to_string = "QR Code")]
+    QrCode,
+    #[strum(to_string = "EAN-13")]
+    Ean13,
+    #[strum(to_string = "UPC-A")]
+    UpcA,
+    #[strum(to_string = "Code 128")]
+    Code128,
+    #[strum(to_string = "Code 39")]
+    Code39,
+}
+
+// Binarization
+
+struct BitMatrix {
+    width: usize,
+    height: usize,
+    bits: Vec<bool>,
+}
+
+impl BitMatrix {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: vec![false; width * height],
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> bool {
+        self.bits[y * self.width + x]
+    }
+
+    fn set(&mut self, x: usize, y: usize, value: bool) {
+        self.bits[y * self.width + x] = value;
+    }
+
+    /// Returns whether the point is dark, treating anything outside the matrix as light.
+    fn get_f32(&self, x: f32, y: f32) -> bool {
+        if x < 0.0 || y < 0.0 || x >= self.width as f32 || y >= self.height as f32 {
+            return false;
+        }
+        self.get(x as usize, y as usize)
+    }
+}
+
+/// Binarizes the image with a single threshold chosen by Otsu's method, which suits screenshots.
+fn binarize_global(image: &GrayImage) -> BitMatrix {
+    let mut histogram = [0u64; 256];
+    for pixel in image.pixels() {
+        histogram[pixel.0[0] as usize] += 1;
+    }
+    let total = u64::from(image.width()) * u64::from(image.height());
+    let sum_all: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(value, count)| value as f64 * *count as f64)
+        .sum();
+
+    let (mut sum_dark, mut weight_dark) = (0.0, 0u64);
+    let (mut threshold, mut best_variance) = (127u8, 0.0);
+    for (value, count) in histogram.iter().enumerate() {
+        weight_dark += count;
+        if weight_dark == 0 {
+            continue;
+        }
+        let weight_light = total - weight_dark;
+        if weight_light == 0 {
+            break;
+        }
+        sum_dark += value as f64 * *count as f64;
+        let mean_dark = sum_dark / weight_dark as f64;
+        let mean_light = (sum_all - sum_dark) / weight_light as f64;
+        let variance = weight_dark as f64 * weight_light as f64 * (mean_dark - mean_light).powi(2);
+        if variance > best_variance {
+            best_variance = variance;
+            threshold = value as u8;
+        }
+    }
+
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let mut matrix = BitMatrix::new(width, height);
+    for (x, y, pixel) in image.enumerate_pixels() {
+        matrix.set(x as usize, y as usize, pixel.0[0] <= threshold);
+    }
+    matrix
+}
+
+/// Binarizes the image against the mean of each pixel's neighbourhood, which copes with uneven
+/// lighting in photos.
+fn binarize_adaptive(image: &GrayImage) -> BitMatrix {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let mut integral = vec![0u64; (width + 1) * (height + 1)];
+    for y in 0..height {
+        let mut row_sum = 0u64;
+        for x in 0..width {
+            row_sum += u64::from(image.get_pixel(x as u32, y as u32).0[0]);
+            integral[(y + 1) * (width + 1) + x + 1] = integral[y * (width + 1) + x + 1] + row_sum;
+        }
+    }
+
+    let radius = (width.min(height) / 8).max(8);
+    let mut matrix = BitMatrix::new(width, height);
+    for y in 0..height {
+        let (top, bottom) = (y.saturating_sub(radius), (y + radius + 1).min(height));
+        for x in 0..width {
+            let (left, right) = (x.saturating_sub(radius), (x + radius + 1).min(width));
+            let area = ((bottom - top) * (right - left)) as u64;
+            let sum = integral[bottom * (width + 1) + right] + integral[top * (width + 1) + left]
+                - integral[top * (width + 1) + right]
+                - integral[bottom * (width + 1) + left];
+            let pixel = u64::from(image.get_pixel(x as u32, y as u32).0[0]);
+            matrix.set(x, y, pixel * area * 10 < sum * 9);
+        }
+    }
+    matrix
+}
+
+// QR code detection
+
+#[derive(Clone, Copy, Debug)]
+struct FinderPattern {
+    x: f32,
+    y: f32,
+    module_size: f32,
+    count: u32,
+}
+
+impl FinderPattern {
+    fn distance(&self, other: &Self) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+fn find_qr_codes(image: &GrayImage) -> Vec<DecodedSymbol> {
+    let mut symbols: Vec<DecodedSymbol> = vec![];
+    for matrix in [binarize_global(image), binarize_adaptive(image)] {
+        let patterns = find_finder_patterns(&matrix);
+        let mut used: Vec<usize> = vec![];
+        for triple in finder_triples(&patterns) {
+            if triple.iter().any(|index| used.contains(index)) {
+                continue;
+            }
+            let corners = triple.map(|index| patterns[index]);
+            if let Some(symbol) = decode_qr_at(&matrix, corners) {
+                used.extend(triple);
+                if !symbols.contains(&symbol) {
+                    symbols.push(symbol);
+                }
+            }
+        }
+        if !symbols.is_empty() {
+            break;
+        }
+    }
+    symbols
+}
+
+/// Checks whether the run lengths look like the 1:1:3:1:1 profile of a finder pattern.
+fn is_finder_profile(counts: &[usize; 5]) -> bool {
+    if counts.contains(&0) {
+        return false;
+    }
+    let total: usize = counts.iter().sum();
+    if total < 7 {
+        return false;
+    }
+    let module_size = total as f32 / 7.0;
+    let max_variance = module_size / 2.0;
+    (module_size - counts[0] as f32).abs() < max_variance
+        && (module_size - counts[1] as f32).abs() < max_variance
+        && (3.0 * module_size - counts[2] as f32).abs() < 3.0 * max_variance
+        && (module_size - counts[3] as f32).abs() < max_variance
+        && (module_size - counts[4] as f32).abs() < max_variance
+}
+
+fn find_finder_patterns(matrix: &BitMatrix) -> Vec<FinderPattern> {
+    let mut patterns: Vec<FinderPattern> = vec![];
+    for y in 0..matrix.height {
+        let mut counts = [0usize; 5];
+        let mut state = 0;
+        for x in 0..=matrix.width {
+            let dark = x < matrix.width && matrix.get(x, y);
+            if dark {
+                if state % 2 == 1 {
+                    state += 1;
+                }
+                counts[state] += 1;
+            } else if state % 2 == 1 {
+                counts[state] += 1;
+            } else if state == 4 {
+                if is_finder_profile(&counts) {
+                    add_finder_candidate(matrix, &mut patterns, &counts, x, y);
+                }
+                counts = [counts[2], counts[3], counts[4], 1, 0];
+                state = 3;
+            } else if counts[state] > 0 {
+                state += 1;
+                counts[state] += 1;
+            }
+        }
+    }
+    patterns
+}
+
+fn add_finder_candidate(
+    matrix: &BitMatrix,
+    patterns: &mut Vec<FinderPattern>,
+    counts: &[usize; 5],
+    end_x: usize,
+    y: usize,
+) {
+    let total: usize = counts.iter().sum();
+    let center_x = end_x as f32 - counts[4] as f32 - counts[3] as f32 - counts[2] as f32 / 2.0;
+    let Some(center_y) = cross_check(matrix, center_x, y as f32, counts[2], total, false) else {
+        return;
+    };
+    let Some(center_x) = cross_check(matrix, center_x, center_y, counts[2], total, true) else {
+        return;
+    };
+    let module_size = total as f32 / 7.0;
+
+    for pattern in patterns.iter_mut() {
+        if (pattern.x - center_x).abs() <= module_size
+            && (pattern.y - center_y).abs() <= module_size
+            && (pattern.module_size - module_size).abs() <= module_size.max(1.0)
+        {
+            let count = pattern.count as f32;
+            pattern.x = (pattern.x * count + center_x) / (count + 1.0);
+            pattern.y = (pattern.y * count + center_y) / (count + 1.0);
+            pattern.module_size = (pattern.module_size * count + module_size) / (count + 1.0);
+            pattern.count += 1;
+            return;
+        }
+    }
+    patterns.push(FinderPattern {
+        x: center_x,
+        y: center_y,
+        module_size,
+        count: 1,
+    });
+}
+
+/// Re-measures a finder pattern candidate perpendicular to the scan line (or along it when
+/// `horizontal`) and returns the refined center coordinate on that axis.
+fn cross_check(
+    matrix: &BitMatrix,
+    x: f32,
+    y: f32,
+    max_count: usize,
+    original_total: usize,
+    horizontal: bool,
+) -> Option<f32> {
+    let (start, limit) = if horizontal {
+        (x as isize, matrix.width as isize)
+    } else {
+        (y as isize, matrix.height as isize)
+    };
+    let is_dark = |position: isize| {
+        if horizontal {
+            matrix.get(position as usize, y as usize)
+        } else {
+            matrix.get(x as usize, position as usize)
+        }
+    };
+    if start < 0 || start >= limit || !is_dark(start) {
+        return None;
+    }
+
+    let mut counts = [0usize; 5];
+    let mut position = start;
+    while position >= 0 && is_dark(position) {
+        counts[2] += 1;
+        position -= 1;
+    }
+    while position >= 0 && !is_dark(position) && counts[1] <= max_count {
+        counts[1] += 1;
+        position -= 1;
+    }
+    if position < 0 || counts[1] > max_count {
+        return None;
+    }
+    while position >= 0 && is_dark(position) && counts[0] <= max_count {
+        counts[0] += 1;
+        position -= 1;
+    }
+    if counts[0] > max_count {
+        return None;
+    }
+
+    position = start + 1;
+    while position < limit && is_dark(position) {
+        counts[2] += 1;
+        position += 1;
+    }
+    while position < limit && !is_dark(position) && counts[3] <= max_count {
+        counts[3] += 1;
+        position += 1;
+    }
+    if position == limit || counts[3] > max_count {
+        return None;
+    }
+    while position < limit && is_dark(position) && counts[4] <= max_count {
+        counts[4] += 1;
+        position += 1;
+    }
+    if counts[4] > max_count {
+        return None;
+    }
+
+    let total: usize = counts.iter().sum();
+    if 5 * total.abs_diff(original_total) >= 2 * original_total || !is_finder_profile(&counts) {
+        return None;
+    }
+    Some(position as f32 - counts[4] as f32 - counts[3] as f32 - counts[2] as f32 / 2.0)
+}
+
+/// Returns plausible finder pattern triples, ordered as top-left, top-right, bottom-left and
+/// sorted from most to least convincing.
+fn finder_triples(patterns: &[FinderPattern]) -> Vec<[usize; 3]> {
+    let mut candidates: Vec<usize> = (0..patterns.len()).collect();
+    candidates.sort_by(|a, b| patterns[*b].count.cmp(&patterns[*a].count));
+    candidates.truncate(16);
+
+    let mut triples: Vec<([usize; 3], f32)> = vec![];
+    for (i, &a) in candidates.iter().enumerate() {
+        for (j, &b) in candidates.iter().enumerate().skip(i + 1) {
+            for &c in candidates.iter().skip(j + 1) {
+                let [pa, pb, pc] = [patterns[a], patterns[b], patterns[c]];
+                let sizes = [pa.module_size, pb.module_size, pc.module_size];
+                let max_size = sizes.iter().cloned().fold(f32::MIN, f32::max);
+                let min_size = sizes.iter().cloned().fold(f32::MAX, f32::min);
+                if max_size > min_size * 1.5 {
+                    continue;
+                }
+
+                // The corner opposite the hypotenuse is the top-left pattern.
+                let (ab, ac, bc) = (pa.distance(&pb), pa.distance(&pc), pb.distance(&pc));
+                let (top_left, mut top_right, mut bottom_left, hypotenuse, leg1, leg2) =
+                    if bc >= ab && bc >= ac {
+                        (a, b, c, bc, ab, ac)
+                    } else if ac >= ab {
+                        (b, a, c, ac, ab, bc)
+                    } else {
+                        (c, a, b, ab, ac, bc)
+                    };
+                if leg1 < 7.0 * min_size || leg2 < 7.0 * min_size {
+                    continue;
+                }
+                let leg_ratio = (leg1 - leg2).abs() / leg1.max(leg2);
+                let hypotenuse_ratio = (hypotenuse * hypotenuse - leg1 * leg1 - leg2 * leg2).abs()
+                    / (hypotenuse * hypotenuse);
+                if leg_ratio > 0.25 || hypotenuse_ratio > 0.25 {
+                    continue;
+                }
+
+                let (tl, tr, bl) = (
+                    patterns[top_left],
+                    patterns[top_right],
+                    patterns[bottom_left],
+                );
+                let cross = (tr.x - tl.x) * (bl.y - tl.y) - (tr.y - tl.y) * (bl.x - tl.x);
+                if cross < 0.0 {
+                    std::mem::swap(&mut top_right, &mut bottom_left);
+                }
+                triples.push((
+                    [top_left, top_right, bottom_left],
+                    leg_ratio + hypotenuse_ratio,
+                ));
+            }
+        }
+    }
+    triples.sort_by(|a, b| a.1.total_cmp(&b.1));
+    triples.into_iter().map(|(triple, _)| triple).collect()
+}
+
+fn decode_qr_at(matrix: &BitMatrix, corners: [FinderPattern; 3]) -> Option<DecodedSymbol> {
+    let [top_left, top_right, bottom_left] = corners;
+    // Run lengths along the scan line overstate the module size of rotated symbols, so measure
+    // it again along the lines joining the finder patterns.
+    let measurements: Vec<f32> = [
+        (top_left, top_right),
+        (top_right, top_left),
+        (top_left, bottom_left),
+        (bottom_left, top_left),
+    ]
+    .iter()
+    .filter_map(|(from, to)| module_size_towards(matrix, from, to))
+    .collect();
+    let module_size = if measurements.is_empty() {
+        (top_left.module_size + top_right.module_size + bottom_left.module_size) / 3.0
+    } else {
+        measurements.iter().sum::<f32>() / measurements.len() as f32
+    };
+    let estimate = ((top_left.distance(&top_right) + top_left.distance(&bottom_left))
+        / (2.0 * module_size))
+        .round() as usize
+        + 7;
+
+    let mut sizes: Vec<usize> = (1..=40).map(qr_size).collect();
+    sizes.sort_by_key(|size| size.abs_diff(estimate));
+    sizes.truncate(3);
+
+    for size in sizes {
+        let transform = PerspectiveTransform::from_finder_patterns(corners, size, None)?;
+        let grid = sample_grid(matrix, &transform, size);
+        let size = match read_version(&grid) {
+            Some(version) if qr_size(version) != size => qr_size(version),
+            _ => size,
+        };
+        let grid = if size == grid.width {
+            grid
+        } else {
+            let transform = PerspectiveTransform::from_finder_patterns(corners, size, None)?;
+            sample_grid(matrix, &transform, size)
+        };
+        if let Some(symbol) = decode_qr_grid(&grid) {
+            return Some(symbol);
+        }
+
+        // Larger symbols drift under perspective distortion, so anchor the fourth corner on the
+        // bottom-right alignment pattern and try again.
+        if size > qr_size(1) {
+            let affine = PerspectiveTransform::from_finder_patterns(corners, size, None)?;
+            let expected = affine.map(size as f32 - 6.5, size as f32 - 6.5);
+            if let Some(alignment) = find_alignment_pattern(matrix, expected, module_size) {
+                let transform =
+                    PerspectiveTransform::from_finder_patterns(corners, size, Some(alignment))?;
+                if let Some(symbol) = decode_qr_grid(&sample_grid(matrix, &transform, size)) {
+                    return Some(symbol);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Walks from the center of a finder pattern towards another one and measures the distance to
+/// the pattern's outer edge, which is 3.5 modules away.
+fn module_size_towards(
+    matrix: &BitMatrix,
+    from: &FinderPattern,
+    to: &FinderPattern,
+) -> Option<f32> {
+    const STEP: f32 = 0.25;
+    let distance = from.distance(to);
+    let (dx, dy) = ((to.x - from.x) / distance, (to.y - from.y) / distance);
+    let mut transitions = 0;
+    let mut dark = true;
+    let mut travelled = 0.0;
+    while travelled < distance / 2.0 {
+        travelled += STEP;
+        if matrix.get_f32(from.x + dx * travelled, from.y + dy * travelled) != dark {
+            dark = !dark;
+            transitions += 1;
+            if transitions == 3 {
+                return Some(travelled / 3.5);
+            }
+        }
+    }
+    None
+}
+
+fn find_alignment_pattern(
+    matrix: &BitMatrix,
+    expected: (f32, f32),
+    module_size: f32,
+) -> Option<(f32, f32)> {
+    let radius = (module_size * 5.0).ceil() as isize;
+    let mut best: Option<((f32, f32), f32)> = None;
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            let (x, y) = (expected.0 + dx as f32, expected.1 + dy as f32);
+            if !matrix.get_f32(x, y) {
+                continue;
+            }
+            let Some(center_x) = alignment_cross_check(matrix, x, y, module_size, true) else {
+                continue;
+            };
+            let Some(center_y) = alignment_cross_check(matrix, center_x, y, module_size, false)
+            else {
+                continue;
+            };
+            let distance = (center_x - expected.0).hypot(center_y - expected.1);
+            if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+                best = Some(((center_x, center_y), distance));
+            }
+        }
+    }
+    best.map(|(center, _)| center)
+}
+
+/// Checks for the dark-light-dark profile through the center of an alignment pattern.
+fn alignment_cross_check(
+    matrix: &BitMatrix,
+    x: f32,
+    y: f32,
+    module_size: f32,
+    horizontal: bool,
+) -> Option<f32> {
+    let step = |offset: f32| {
+        if horizontal {
+            matrix.get_f32(x + offset, y)
+        } else {
+            matrix.get_f32(x, y + offset)
+        }
+    };
+    let max_run = (module_size * 1.5).ceil();
+    let run = |direction: f32, start: f32, dark: bool| {
+        let mut length = 0.0;
+        while length <= max_run && step(direction * (start + length)) == dark {
+            length += 1.0;
+        }
+        length
+    };
+
+    let before = run(-1.0, 1.0, true);
+    let after = run(1.0, 0.0, true);
+    let center = before + after;
+    let light_before = run(-1.0, before + 1.0, false);
+    let light_after = run(1.0, after, false);
+    let in_range = |length: f32| length >= module_size * 0.5 && length <= max_run;
+    if !in_range(center) || !in_range(light_before) || !in_range(light_after) {
+        return None;
+    }
+    let offset = (after - before) / 2.0;
+    Some(if horizontal { x + offset } else { y + offset })
+}
+
+/// Maps module coordinates onto image coordinates.
+struct PerspectiveTransform {
+    coefficients: [f64; 8],
+}
+
+impl PerspectiveTransform {
+    fn from_finder_patterns(
+        corners: [FinderPattern; 3],
+        size: usize,
+        alignment: Option<(f32, f32)>,
+    ) -> Option<Self> {
+        let [top_left, top_right, bottom_left] = corners;
+        let far = size as f32 - 3.5;
+        let (module_corner, image_corner) = match alignment {
+            Some(alignment) => ((size as f32 - 6.5, size as f32 - 6.5), alignment),
+            None => (
+                (far, far),
+                (
+                    top_right.x + bottom_left.x - top_left.x,
+                    top_right.y + bottom_left.y - top_left.y,
+                ),
+            ),
+        };
+        Self::quad_to_quad(
+            [(3.5, 3.5), (far, 3.5), (3.5, far), module_corner],
+            [
+                (top_left.x, top_left.y),
+                (top_right.x, top_right.y),
+                (bottom_left.x, bottom_left.y),
+                image_corner,
+            ],
+        )
+    }
+
+    fn quad_to_quad(source: [(f32, f32); 4], target: [(f32, f32); 4]) -> Option<Self> {
+        let mut system = [[0f64; 9]; 8];
+        for (i, ((u, v), (x, y))) in source.iter().zip(target.iter()).enumerate() {
+            let (u, v, x, y) = (*u as f64, *v as f64, *x as f64, *y as f64);
+            system[2 * i] = [u, v, 1.0, 0.0, 0.0, 0.0, -u * x, -v * x, x];
+            system[2 * i + 1] = [0.0, 0.0, 0.0, u, v, 1.0, -u * y, -v * y, y];
+        }
+
+        // Gaussian elimination with partial pivoting.
+        for column in 0..8 {
+            let pivot = (column..8).max_by(|a, b| {
+                system[*a][column]
+                    .abs()
+                    .total_cmp(&system[*b][column].abs())
+            })?;
+            if system[pivot][column].abs() < 1e-12 {
+                return None;
+            }
+            system.swap(column, pivot);
+            let pivot_row = system[column];
+            for (row, equation) in system.iter_mut().enumerate() {
+                if row != column {
+                    let factor = equation[column] / pivot_row[column];
+                    for (value, pivot_value) in equation.iter_mut().zip(pivot_row).skip(column) {
+                        *value -= factor * pivot_value;
+                    }
+                }
+            }
+        }
+
+        let mut coefficients = [0f64; 8];
+        for (i, coefficient) in coefficients.iter_mut().enumerate() {
+            *coefficient = system[i][8] / system[i][i];
+        }
+        Some(Self { coefficients })
+    }
+
+    fn map(&self, u: f32, v: f32) -> (f32, f32) {
+        let [a, b, c, d, e, f, g, h] = self.coefficients;
+        let (u, v) = (u as f64, v as f64);
+        let denominator = g * u + h * v + 1.0;
+        (
+            ((a * u + b * v + c) / denominator) as f32,
+            ((d * u + e * v + f) / denominator) as f32,
+        )
+    }
+}
+
+fn sample_grid(matrix: &BitMatrix, transform: &PerspectiveTransform, size: usize) -> BitMatrix {
+    let mut grid = BitMatrix::new(size, size);
+    for y in 0..size {
+        for x in 0..size {
+            let (image_x, image_y) = transform.map(x as f32 + 0.5, y as f32 + 0.5);
+            grid.set(x, y, matrix.get_f32(image_x, image_y));
+        }
+    }
+    grid
+}
+
+// QR code decoding
+
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    // Version: (index 0 is padding)
+    //0, 1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ], // Low
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ], // Medium
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ], // Quartile
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ], // High
+];
+
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ], // Low
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ], // Medium
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ], // Quartile
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ], // High
+];
+
+const ALPHANUMERIC_CHARSET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+fn qr_size(version: u8) -> usize {
+    usize::from(version) * 4 + 17
+}
+
+fn ecc_index(ecc: Ecc) -> usize {
+    match ecc {
+        Ecc::Low => 0,
+        Ecc::Medium => 1,
+        Ecc::Quartile => 2,
+        Ecc::High => 3,
+    }
+}
+
+fn alignment_pattern_positions(version: u8) -> Vec<usize> {
+    if version == 1 {
+        return vec![];
+    }
+    let version = usize::from(version);
+    let count = version / 7 + 2;
+    let step = if version == 32 {
+        26
+    } else {
+        (version * 4 + count * 2 + 1) / (count * 2 - 2) * 2
+    };
+    let mut positions = vec![6; count];
+    let mut position = qr_size(version as u8) - 7;
+    for slot in positions.iter_mut().skip(1).rev() {
+        *slot = position;
+        position = position.saturating_sub(step);
+    }
+    positions
+}
+
+fn raw_data_modules(version: u8) -> usize {
+    let version = usize::from(version);
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let count = version / 7 + 2;
+        result -= (25 * count - 10) * count - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn function_pattern_mask(version: u8) -> BitMatrix {
+    let size = qr_size(version);
+    let mut mask = BitMatrix::new(size, size);
+    let mut fill = |left: usize, top: usize, width: usize, height: usize| {
+        for y in top..(top + height).min(size) {
+            for x in left..(left + width).min(size) {
+                mask.set(x, y, true);
+            }
+        }
+    };
+
+    // Finder patterns, separators and format information.
+    fill(0, 0, 9, 9);
+    fill(size - 8, 0, 8, 9);
+    fill(0, size - 8, 9, 8);
+    // Timing patterns.
+    fill(6, 0, 1, size);
+    fill(0, 6, size, 1);
+
+    let positions = alignment_pattern_positions(version);
+    let last = positions.len().saturating_sub(1);
+    for (i, &y) in positions.iter().enumerate() {
+        for (j, &x) in positions.iter().enumerate() {
+            if (i == 0 && j == 0) || (i == 0 && j == last) || (i == last && j == 0) {
+                continue;
+            }
+            fill(x - 2, y - 2, 5, 5);
+        }
+    }
+
+    if version >= 7 {
+        fill(size - 11, 0, 3, 6);
+        fill(0, size - 11, 6, 3);
+    }
+    mask
+}
+
+fn hamming_closest(bits: u32, candidates: impl Iterator<Item = (u32, u32)>) -> Option<u32> {
+    candidates
+        .map(|(value, codeword)| (value, (bits ^ codeword).count_ones()))
+        .filter(|(_, distance)| *distance <= 3)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(value, _)| value)
+}
+
+fn read_format(grid: &BitMatrix) -> Option<(Ecc, u8)> {
+    let size = grid.width;
+    let bit = |x: usize, y: usize, index: usize| u32::from(grid.get(x, y)) << index;
+
+    let mut first = 0u32;
+    for i in 0..6 {
+        first |= bit(8, i, i);
+    }
+    first |= bit(8, 7, 6) | bit(8, 8, 7) | bit(7, 8, 8);
+    for i in 9..15 {
+        first |= bit(14 - i, 8, i);
+    }
+    let mut second = 0u32;
+    for i in 0..8 {
+        second |= bit(size - 1 - i, 8, i);
+    }
+    for i in 8..15 {
+        second |= bit(8, size - 15 + i, i);
+    }
+
+    let codewords = (0..32u32).map(|data| {
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        (data, ((data << 10) | remainder) ^ 0x5412)
+    });
+    let data =
+        hamming_closest(first, codewords.clone()).or_else(|| hamming_closest(second, codewords))?;
+
+    let ecc = match data >> 3 {
+        1 => Ecc::Low,
+        0 => Ecc::Medium,
+        3 => Ecc::Quartile,
+        _ => Ecc::High,
+    };
+    Some((ecc, (data & 7) as u8))
+}
+
+fn read_version(grid: &BitMatrix) -> Option<u8> {
+    let size = grid.width;
+    if size < qr_size(7) {
+        return None;
+    }
+    let (mut first, mut second) = (0u32, 0u32);
+    for i in 0..18 {
+        let (a, b) = (size - 11 + i % 3, i / 3);
+        first |= u32::from(grid.get(a, b)) << i;
+        second |= u32::from(grid.get(b, a)) << i;
+    }
+    let codewords = (7..=40u32).map(|version| {
+        let mut remainder = version;
+        for _ in 0..12 {
+            remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+        }
+        (version, (version << 12) | remainder)
+    });
+    hamming_closest(first, codewords.clone())
+        .or_else(|| hamming_closest(second, codewords))
+        .map(|version| version as u8)
+}
+
+fn is_masked(mask: u8, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+fn read_codewords(grid: &BitMatrix, version: u8, mask: u8) -> Vec<u8> {
+    let size = grid.width;
+    let function_mask = function_pattern_mask(version);
+    let mut codewords = vec![0u8; raw_data_modules(version) / 8];
+    let mut bit_index = 0;
+
+    let mut right = size - 1;
+    loop {
+        if right == 6 {
+            right = 5;
+        }
+        for vertical in 0..size {
+            for j in 0..2 {
+                let x = right - j;
+                let upward = (right + 1) & 2 == 0;
+                let y = if upward {
+                    size - 1 - vertical
+                } else {
+                    vertical
+                };
+                if function_mask.get(x, y) || bit_index >= codewords.len() * 8 {
+                    continue;
+                }
+                if grid.get(x, y) != is_masked(mask, x, y) {
+                    codewords[bit_index / 8] |= 0x80 >> (bit_index % 8);
+                }
+                bit_index += 1;
+            }
+        }
+        if right < 2 {
+            break;
+        }
+        right -= 2;
+    }
+    codewords
+}
+
+fn decode_qr_grid(grid: &BitMatrix) -> Option<DecodedSymbol> {
+    let size = grid.width;
+    if size < qr_size(1) || !(size - 17).is_multiple_of(4) {
+        return None;
+    }
+    let version = ((size - 17) / 4) as u8;
+    let (ecc, mask) = read_format(grid)?;
+    let codewords = read_codewords(grid, version, mask);
+
+    // De-interleave the codewords into their blocks, correct them and concatenate the data.
+    let num_blocks = usize::from(NUM_ERROR_CORRECTION_BLOCKS[ecc_index(ecc)][usize::from(version)]);
+    let ecc_len = usize::from(ECC_CODEWORDS_PER_BLOCK[ecc_index(ecc)][usize::from(version)]);
+    let num_short_blocks = num_blocks - codewords.len() % num_blocks;
+    let short_block_len = codewords.len() / num_blocks;
+
+    let mut blocks = vec![Vec::with_capacity(short_block_len + 1); num_blocks];
+    let mut codeword_iter = codewords.iter();
+    for i in 0..=short_block_len {
+        for (j, block) in blocks.iter_mut().enumerate() {
+            if i != short_block_len - ecc_len || j >= num_short_blocks {
+                block.push(*codeword_iter.next()?);
+            }
+        }
+    }
+
+    let mut data = vec![];
+    for mut block in blocks {
+        if !correct_errors(&mut block, ecc_len) {
+            return None;
+        }
+        data.extend_from_slice(&block[..block.len() - ecc_len]);
+    }
+
+    Some(DecodedSymbol {
+        symbology: Symbology::QrCode,
+        payload: decode_segments(&data, version)?,
+        qr_info: Some(QrInfo { version, ecc, mask }),
+    })
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    fn read(&mut self, count: usize) -> Option<u32> {
+        if count > self.remaining() {
+            return None;
+        }
+        let mut value = 0u32;
+        for _ in 0..count {
+            let bit = (self.data[self.position / 8] >> (7 - self.position % 8)) & 1;
+            value = (value << 1) | u32::from(bit);
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
+
+fn decode_segments(data: &[u8], version: u8) -> Option<String> {
+    let mut reader = BitReader { data, position: 0 };
+    let size_class = match version {
+        1..=9 => 0,
+        10..=26 => 1,
+        _ => 2,
+    };
+    let mut bytes: Vec<u8> = vec![];
+
+    while reader.remaining() >= 4 {
+        match reader.read(4)? {
+            // Terminator
+            0b0000 => break,
+            // Numeric
+            0b0001 => {
+                let mut count = reader.read([10, 12, 14][size_class])?;
+                while count > 0 {
+                    let digits = count.min(3);
+                    let value = reader.read([0, 4, 7, 10][digits as usize])?;
+                    if value >= 10u32.pow(digits) {
+                        return None;
+                    }
+                    bytes.extend(format!("{:0width$}", value, width = digits as usize).bytes());
+                    count -= digits;
+                }
+            }
+            // Alphanumeric
+            0b0010 => {
+                let mut count = reader.read([9, 11, 13][size_class])?;
+                while count >= 2 {
+                    let value = reader.read(11)? as usize;
+                    if value >= 45 * 45 {
+                        return None;
+                    }
+                    bytes.push(ALPHANUMERIC_CHARSET[value / 45]);
+                    bytes.push(ALPHANUMERIC_CHARSET[value % 45]);
+                    count -= 2;
+                }
+                if count == 1 {
+                    bytes.push(*ALPHANUMERIC_CHARSET.get(reader.read(6)? as usize)?);
+                }
+            }
+            // Byte
+            0b0100 => {
+                let count = reader.read([8, 16, 16][size_class])?;
+                for _ in 0..count {
+                    bytes.push(reader.read(8)? as u8);
+                }
+            }
+            // Kanji, shown as replacement characters since there is no Shift JIS table to hand
+            0b1000 => {
+                let count = reader.read([8, 10, 12][size_class])?;
+                for _ in 0..count {
+                    reader.read(13)?;
+                    bytes.extend("\u{FFFD}".bytes());
+                }
+            }
+            // ECI designator
+            0b0111 => {
+                let first = reader.read(8)?;
+                if first & 0x80 == 0x80 {
+                    reader.read(if first & 0xC0 == 0x80 { 8 } else { 16 })?;
+                }
+            }
+            // Structured append
+            0b0011 => {
+                reader.read(16)?;
+            }
+            // FNC1 in first position
+            0b0101 => {}
+            // FNC1 in second position
+            0b1001 => {
+                reader.read(8)?;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(match String::from_utf8(bytes) {
+        Ok(text) => text,
+        // Fall back to ISO-8859-1, the default QR code character set.
+        Err(err) => err.into_bytes().iter().map(|byte| *byte as char).collect(),
+    })
+}
+
+// Reed-Solomon error correction over GF(256)
+
+const GF_EXP: [u8; 512] = {
+    let mut table = [0u8; 512];
+    let mut value: u16 = 1;
+    let mut i = 0;
+    while i < 512 {
+        table[i] = value as u8;
+        value <<= 1;
+        if value & 0x100 != 0 {
+            value ^= 0x11D;
+        }
+        i += 1;
+    }
+    table
+};
+
+const GF_LOG: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[GF_EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        GF_EXP[GF_LOG[a as usize] as usize + GF_LOG[b as usize] as usize]
+    }
+}
+
+fn gf_div(a: u8, b: u8) -> u8 {
+    if a == 0 {
+        0
+    } else {
+        GF_EXP[(GF_LOG[a as usize] as usize + 255 - GF_LOG[b as usize] as usize) % 255]
+    }
+}
+
+/// Evaluates a polynomial whose coefficients are stored lowest degree first.
+fn gf_eval(polynomial: &[u8], x: u8) -> u8 {
+    polynomial
+        .iter()
+        .rev()
+        .fold(0, |acc, coefficient| gf_mul(acc, x) ^ coefficient)
+}
+
+/// Corrects the block in place, returning false if there are too many errors to recover.
+fn correct_errors(block: &mut [u8], ecc_len: usize) -> bool {
+    let syndromes = |block: &[u8]| -> Vec<u8> {
+        (0..ecc_len)
+            .map(|i| {
+                block
+                    .iter()
+                    .fold(0, |acc, codeword| gf_mul(acc, GF_EXP[i]) ^ codeword)
+            })
+            .collect()
+    };
+    let syndrome = syndromes(block);
+    if syndrome.iter().all(|value| *value == 0) {
+        return true;
+    }
+
+    // Berlekamp-Massey: find the error locator polynomial.
+    let mut locator = vec![1u8];
+    let mut previous = vec![1u8];
+    let (mut errors, mut shift, mut previous_discrepancy) = (0usize, 1usize, 1u8);
+    for k in 0..ecc_len {
+        let mut discrepancy = syndrome[k];
+        for i in 1..=errors.min(locator.len() - 1) {
+            discrepancy ^= gf_mul(locator[i], syndrome[k - i]);
+        }
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+        let coefficient = gf_div(discrepancy, previous_discrepancy);
+        let mut next = locator.clone();
+        if next.len() < previous.len() + shift {
+            next.resize(previous.len() + shift, 0);
+        }
+        for (i, value) in previous.iter().enumerate() {
+            next[i + shift] ^= gf_mul(coefficient, *value);
+        }
+        if 2 * errors <= k {
+            errors = k + 1 - errors;
+            previous = locator;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+        locator = next;
+    }
+    if 2 * errors > ecc_len {
+        return false;
+    }
+
+    // Chien search: find the error positions.
+    let n = block.len();
+    let positions: Vec<usize> = (0..n)
+        .filter(|j| gf_eval(&locator, GF_EXP[(255 - (n - 1 - j) % 255) % 255]) == 0)
+        .collect();
+    if positions.len() != errors {
+        return false;
+    }
+
+    // Forney: compute the error magnitudes.
+    let evaluator: Vec<u8> = (0..ecc_len)
+        .map(|i| {
+            (0..=i).fold(0, |acc, j| {
+                acc ^ gf_mul(syndrome[j], *locator.get(i - j).unwrap_or(&0))
+            })
+        })
+        .collect();
+    let derivative: Vec<u8> = locator
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, value)| if i % 2 == 1 { *value } else { 0 })
+        .collect();
+    for position in positions {
+        let power = (n - 1 - position) % 255;
+        let x_inverse = GF_EXP[(255 - power) % 255];
+        let denominator = gf_eval(&derivative, x_inverse);
+        if denominator == 0 {
+            return false;
+        }
+        let magnitude = gf_mul(
+            GF_EXP[power],
+            gf_div(gf_eval(&evaluator, x_inverse), denominator),
+        );
+        block[position] ^= magnitude;
+    }
+
+    syndromes(block).iter().all(|value| *value == 0)
+}
+
+// Linear barcodes
+
+const LINEAR_SCAN_LINES: u32 = 24;
+
+fn find_linear_barcodes(image: &GrayImage) -> Vec<DecodedSymbol> {
+    let mut symbols: Vec<DecodedSymbol> = vec![];
+    for line in 1..LINEAR_SCAN_LINES {
+        let y = image.height() * line / LINEAR_SCAN_LINES;
+        let row: Vec<u8> = (0..image.width())
+            .map(|x| image.get_pixel(x, y).0[0])
+            .collect();
+        let Some(runs) = row_runs(&row) else {
+            continue;
+        };
+        let reversed: Vec<usize> = runs.iter().rev().cloned().collect();
+
+        for runs in [runs, reversed] {
+            let symbol = decode_ean13(&runs)
+                .or_else(|| decode_code128(&runs))
+                .or_else(|| decode_code39(&runs));
+            if let Some(symbol) = symbol {
+                if !symbols.contains(&symbol) {
+                    symbols.push(symbol);
+                }
+            }
+        }
+    }
+    symbols
+}
+
+/// Converts a row of pixels into alternating run lengths, starting and ending with a light run.
+fn row_runs(row: &[u8]) -> Option<Vec<usize>> {
+    let (min, max) = row.iter().fold((u8::MAX, u8::MIN), |(min, max), pixel| {
+        (min.min(*pixel), max.max(*pixel))
+    });
+    if max.saturating_sub(min) < 48 {
+        return None;
+    }
+    let threshold = ((u16::from(min) + u16::from(max)) / 2) as u8;
+
+    let mut runs = vec![0usize];
+    let mut dark = false;
+    for pixel in row {
+        if (*pixel < threshold) != dark {
+            dark = !dark;
+            runs.push(0);
+        }
+        *runs.last_mut()? += 1;
+    }
+    if dark {
+        runs.push(0);
+    }
+    Some(runs)
+}
+
+/// Compares run lengths against a module-width pattern, returning the average deviation per
+/// element in modules if no single element is too far off.
+fn pattern_variance(runs: &[usize], pattern: &[u8]) -> Option<f32> {
+    let total_runs: usize = runs.iter().sum();
+    let total_pattern: u32 = pattern.iter().map(|width| u32::from(*width)).sum();
+    if total_runs == 0 {
+        return None;
+    }
+    let module = total_runs as f32 / total_pattern as f32;
+    let mut variance = 0.0;
+    for (run, width) in runs.iter().zip(pattern) {
+        let deviation = (*run as f32 / module - f32::from(*width)).abs();
+        if deviation > 0.7 {
+            return None;
+        }
+        variance += deviation;
+    }
+    Some(variance / pattern.len() as f32)
+}
+
+fn best_pattern<'a>(runs: &[usize], patterns: impl Iterator<Item = &'a [u8]>) -> Option<usize> {
+    patterns
+        .enumerate()
+        .filter_map(|(index, pattern)| Some((index, pattern_variance(runs, pattern)?)))
+        .filter(|(_, variance)| *variance < 0.48)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+}
+
+/// Checks that the light run before `start` is wide enough to be a quiet zone.
+fn has_quiet_zone(runs: &[usize], start: usize, module: f32) -> bool {
+    start > 0 && runs[start - 1] as f32 >= module * 5.0
+}
+
+fn decode_ean13(runs: &[usize]) -> Option<DecodedSymbol> {
+    const LENGTH: usize = 59;
+    let reversed: Vec<[u8; 4]> = EAN_DIGIT_PATTERNS
+        .iter()
+        .map(|pattern| [pattern[3], pattern[2], pattern[1], pattern[0]])
+        .collect();
+
+    for start in (1..runs.len().saturating_sub(LENGTH)).step_by(2) {
+        let module = runs[start..start + LENGTH].iter().sum::<usize>() as f32 / 95.0;
+        let guard_ok = |offset: usize, count: usize| {
+            runs[start + offset..start + offset + count]
+                .iter()
+                .all(|run| (*run as f32 / module - 1.0).abs() < 0.7)
+        };
+        if !has_quiet_zone(runs, start, module)
+            || !guard_ok(0, 3)
+            || !guard_ok(27, 5)
+            || !guard_ok(56, 3)
+        {
+            continue;
+        }
+
+        let mut digits = vec![];
+        let mut parity = 0u8;
+        let mut valid = true;
+        for i in 0..12 {
+            let offset = start + 3 + 4 * i + if i >= 6 { 5 } else { 0 };
+            let digit_runs = &runs[offset..offset + 4];
+            let l_match = best_pattern(digit_runs, EAN_DIGIT_PATTERNS.iter().map(|p| &p[..]));
+            let g_match = if i < 6 {
+                best_pattern(digit_runs, reversed.iter().map(|p| &p[..]))
+            } else {
+                None
+            };
+            let digit = match (l_match, g_match) {
+                (Some(l), Some(g)) => {
+                    let l_variance = pattern_variance(digit_runs, &EAN_DIGIT_PATTERNS[l]);
+                    let g_variance = pattern_variance(digit_runs, &reversed[g]);
+                    if g_variance < l_variance {
+                        parity |= 1 << (5 - i);
+                        g
+                    } else {
+                        l
+                    }
+                }
+                (Some(l), None) => l,
+                (None, Some(g)) => {
+                    parity |= 1 << (5 - i);
+                    g
+                }
+                (None, None) => {
+                    valid = false;
+                    break;
+                }
+            };
+            digits.push(digit as u8);
+        }
+        if !valid {
+            continue;
+        }
+        let Some(first) = EAN_FIRST_DIGIT_PARITY.iter().position(|p| *p == parity) else {
+            continue;
+        };
+        digits.insert(0, first as u8);
+        if ean_check_digit(&digits[..12]) != digits[12] {
+            continue;
+        }
+
+        let payload: String = digits
+            .iter()
+            .map(|digit| char::from(b'0' + digit))
+            .collect();
+        return Some(if first == 0 {
+            DecodedSymbol {
+                symbology: Symbology::UpcA,
+                payload: payload[1..].to_string(),
+                qr_info: None,
+            }
+        } else {
+            DecodedSymbol {
+                symbology: Symbology::Ean13,
+                payload,
+                qr_info: None,
+            }
+        });
+    }
+    None
+}
+
+fn decode_code128(runs: &[usize]) -> Option<DecodedSymbol> {
+    for start in (1..runs.len().saturating_sub(6)).step_by(2) {
+        let module = runs[start..start + 6].iter().sum::<usize>() as f32 / 11.0;
+        if !has_quiet_zone(runs, start, module) {
+            continue;
+        }
+        let Some(start_code) = best_pattern(
+            &runs[start..start + 6],
+            CODE128_PATTERNS[103..106].iter().cloned(),
+        ) else {
+            continue;
+        };
+
+        let mut values = vec![103 + start_code as u8];
+        let mut position = start + 6;
+        let mut terminated = false;
+        while position + 7 <= runs.len() && values.len() < 100 {
+            if pattern_variance(&runs[position..position + 7], CODE128_PATTERNS[106])
+                .is_some_and(|variance| variance < 0.48)
+            {
+                terminated = true;
+                break;
+            }
+            let Some(value) = best_pattern(
+                &runs[position..position + 6],
+                CODE128_PATTERNS[..106].iter().cloned(),
+            ) else {
+                break;
+            };
+            values.push(value as u8);
+            position += 6;
+        }
+        if !terminated || values.len() < 3 {
+            continue;
+        }
+
+        let check = values.pop()?;
+        let sum: usize = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| usize::from(*value) * i.max(1))
+            .sum();
+        if sum % 103 != usize::from(check) {
+            continue;
+        }
+        if let Some(payload) = code128_text(&values) {
+            return Some(DecodedSymbol {
+                symbology: Symbology::Code128,
+                payload,
+                qr_info: None,
+            });
+        }
+    }
+    None
+}
+
+/// Interprets Code 128 symbol values (starting with the start code) as text.
+fn code128_text(values: &[u8]) -> Option<String> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum CodeSet {
+        A,
+        B,
+        C,
+    }
+
+    let mut code_set = match values.first()? {
+        103 => CodeSet::A,
+        104 => CodeSet::B,
+        _ => CodeSet::C,
+    };
+    let mut text = String::new();
+    let mut shift = false;
+    for &value in &values[1..] {
+        let current = if shift {
+            shift = false;
+            if code_set == CodeSet::A {
+                CodeSet::B
+            } else {
+                CodeSet::A
+            }
+        } else {
+            code_set
+        };
+        match (current, value) {
+            (CodeSet::C, 0..=99) => text.push_str(&format!("{:02}", value)),
+            (CodeSet::A, 0..=63) | (CodeSet::B, 0..=95) => text.push(char::from(value + 32)),
+            (CodeSet::A, 64..=95) => text.push(char::from(value - 64)),
+            (CodeSet::A | CodeSet::B, 98) => shift = true,
+            (CodeSet::A | CodeSet::B, 99) => code_set = CodeSet::C,
+            (CodeSet::A, 100) | (CodeSet::C, 100) => code_set = CodeSet::B,
+            (CodeSet::B, 101) | (CodeSet::C, 101) => code_set = CodeSet::A,
+            // FNC1-4 carry no text of their own.
+            _ => {}
+        }
+    }
+    Some(text)
+}
+
+fn decode_code39(runs: &[usize]) -> Option<DecodedSymbol> {
+    let asterisk = CODE39_ALPHABET.len() - 1;
+    for start in (1..runs.len().saturating_sub(9)).step_by(2) {
+        let Some((first, narrow)) = code39_character(&runs[start..start + 9]) else {
+            continue;
+        };
+        if first != asterisk || !has_quiet_zone(runs, start, narrow) {
+            continue;
+        }
+
+        let mut text = String::new();
+        let mut position = start + 10;
+        while position + 9 <= runs.len() {
+            let Some((character, _)) = code39_character(&runs[position..position + 9]) else {
+                break;
+            };
+            if character == asterisk {
+                if !text.is_empty()
+                    && runs
+                        .get(position + 9)
+                        .is_some_and(|run| *run as f32 >= narrow * 5.0)
+                {
+                    return Some(DecodedSymbol {
+                        symbology: Symbology::Code39,
+                        payload: text,
+                        qr_info: None,
+                    });
+                }
+                break;
+            }
+            text.push(char::from(CODE39_ALPHABET[character]));
+            position += 10;
+        }
+    }
+    None
+}
+
+/// Decodes nine Code 39 elements into an alphabet index, also returning the narrow width.
+fn code39_character(runs: &[usize]) -> Option<(usize, f32)> {
+    let mut sorted = runs.to_vec();
+    sorted.sort_unstable();
+    let (narrow_max, wide_min) = (sorted[5], sorted[6]);
+    if narrow_max == 0 || (wide_min as f32) < narrow_max as f32 * 1.5 {
+        return None;
+    }
+    let pattern = runs.iter().fold(0u16, |pattern, run| {
+        (pattern << 1) | u16::from(*run >= wide_min)
+    });
+    let narrow = sorted[..6].iter().sum::<usize>() as f32 / 6.0;
+    CODE39_PATTERNS
+        .iter()
+        .position(|candidate| *candidate == pattern)
+        .map(|index| (index, narrow))
+}
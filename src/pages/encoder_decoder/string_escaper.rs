@@ -0,0 +1,821 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaQuoteLeft;
+use std::{iter::Peekable, str::Chars};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{SelectForm, SelectFormEnum, SwitchInput, TextInput};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "String Escape / Unescape",
+    short_title: "String Escape",
+    description: "Escape and unescape string literals for programming languages",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaQuoteLeft> = WidgetIcon { icon: FaQuoteLeft };
+
+/// Characters with a special meaning in regular expressions, matching `regex::escape`.
+const REGEX_META_CHARACTERS: &str = "\\.+*?()|[]{}^$#&-~";
+
+pub fn StringEscaper() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(EncoderValue {
+            text: EncoderText::default(),
+            language: StringLanguage::default(),
+            escape_non_ascii: false,
+            error: None,
+        })
+    });
+    let language = value_context.read().language;
+    let escape_non_ascii = value_context.read().escape_non_ascii;
+    let error = value_context.read().error.clone();
+    let raw_string = match language {
+        StringLanguage::Rust => rust_raw_string(&value_context.read().text.decoded_value),
+        _ => None,
+    };
+
+    rsx! {
+        div { class: "widget",
+            div { class: "widget-params",
+                SelectForm::<StringLanguage> {
+                    label: "Language",
+                    oninput: move |language: StringLanguage| {
+                        value_context
+                            .with_mut(|value| {
+                                value.language = language;
+                                value.refresh();
+                            });
+                    },
+                    value: language,
+                }
+            }
+            if language.supports_non_ascii_escapes() {
+                div { class: "widget-switches",
+                    SwitchInput {
+                        label: "Escape non-ASCII characters",
+                        checked: escape_non_ascii,
+                        oninput: move |is_enabled| {
+                            value_context
+                                .with_mut(|value| {
+                                    value.escape_non_ascii = is_enabled;
+                                    value.refresh();
+                                });
+                        },
+                    }
+                }
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary: false,
+                encoded_label: "Escaped",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.escape();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary: false,
+                encoded_label: "Escaped",
+                oninput: move |input: String| value_context.with_mut(|value| value.unescape(input)),
+            }
+            if let Some(raw_string) = raw_string {
+                TextInput {
+                    label: "Raw String Literal",
+                    value: "{raw_string}",
+                    readonly: true,
+                }
+            }
+            if let Some(error) = error {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EncoderValue {
+    text: EncoderText,
+    language: StringLanguage,
+    escape_non_ascii: bool,
+    error: Option<String>,
+}
+
+impl EncoderValue {
+    fn escape(&mut self) {
+        self.text.encoded_value = self
+            .language
+            .escape(&self.text.decoded_value, self.escape_non_ascii);
+        self.error = None;
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn unescape(&mut self, input: String) {
+        match self.language.unescape(&input) {
+            Ok(unescaped) => {
+                self.text.set_decoded_bytes(unescaped.into_bytes());
+                self.error = None;
+            }
+            Err(err) => {
+                self.text.set_decoded_bytes(Vec::new());
+                self.error = Some(err);
+            }
+        }
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Re-escapes the text or re-unescapes the literal, whichever was typed last, after an
+    /// option changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.escape(),
+            Direction::Decode => self.unescape(self.text.encoded_value.clone()),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum StringLanguage {
+    #[default]
+    #[strum(to_string = "JSON")]
+    Json,
+    Rust,
+    C,
+    Java,
+    Python,
+    #[strum(to_string = "SQL")]
+    Sql,
+    #[strum(to_string = "Shell (single quotes)")]
+    ShellSingleQuoted,
+    #[strum(to_string = "Shell (double quotes)")]
+    ShellDoubleQuoted,
+    #[strum(to_string = "Regular Expression")]
+    Regex,
+}
+
+impl SelectFormEnum for StringLanguage {}
+
+impl From<StringLanguage> for String {
+    fn from(language: StringLanguage) -> Self {
+        language.to_string()
+    }
+}
+
+impl StringLanguage {
+    fn supports_non_ascii_escapes(&self) -> bool {
+        matches!(
+            self,
+            Self::Json | Self::Rust | Self::C | Self::Java | Self::Python
+        )
+    }
+
+    /// Escapes the input as a complete literal, including its quotes.
+    fn escape(&self, input: &str, escape_non_ascii: bool) -> String {
+        match self {
+            Self::Json => quote(input, "\"", |character| {
+                escape_json(character, escape_non_ascii)
+            }),
+            Self::Rust => quote(input, "\"", |character| {
+                escape_rust(character, escape_non_ascii)
+            }),
+            Self::C => escape_c(input, escape_non_ascii),
+            Self::Java => quote(input, "\"", |character| {
+                escape_java(character, escape_non_ascii)
+            }),
+            Self::Python => escape_python(input, escape_non_ascii),
+            Self::Sql => format!("'{}'", input.replace('\'', "''")),
+            Self::ShellSingleQuoted => format!("'{}'", input.replace('\'', r"'\''")),
+            Self::ShellDoubleQuoted => quote(input, "\"", |character| {
+                matches!(character, '"' | '\\' | '$' | '`').then(|| format!("\\{character}"))
+            }),
+            Self::Regex => input
+                .chars()
+                .map(
+                    |character| match REGEX_META_CHARACTERS.contains(character) {
+                        true => format!("\\{character}"),
+                        false => character.to_string(),
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// Unescapes a literal. The surrounding quotes are optional so that the contents of a
+    /// literal can be pasted on their own.
+    fn unescape(&self, input: &str) -> Result<String, String> {
+        match self {
+            Self::Json => serde_json::from_str(&format!("\"{}\"", strip_quotes(input, "\"")))
+                .map_err(|err| err.to_string()),
+            Self::Rust => unescape_rust(input),
+            Self::C => unescape_c(strip_quotes(input, "\"")),
+            Self::Java => unescape_java(strip_quotes(input, "\"")),
+            Self::Python => unescape_python(input),
+            Self::Sql => Ok(strip_quotes(input, "'").replace("''", "'")),
+            Self::ShellSingleQuoted | Self::ShellDoubleQuoted => unescape_shell(input),
+            Self::Regex => unescape_regex(input),
+        }
+    }
+}
+
+/// Wraps the input in quotes, replacing each character the escape function returns a
+/// sequence for.
+fn quote(input: &str, quote: &str, escape: impl Fn(char) -> Option<String>) -> String {
+    let mut quoted = quote.to_string();
+    for character in input.chars() {
+        match escape(character) {
+            Some(escaped) => quoted.push_str(&escaped),
+            None => quoted.push(character),
+        }
+    }
+    quoted.push_str(quote);
+    quoted
+}
+
+fn strip_quotes<'a>(input: &'a str, quote: &str) -> &'a str {
+    match input.len() >= 2 * quote.len() {
+        true => input
+            .strip_prefix(quote)
+            .and_then(|input| input.strip_suffix(quote))
+            .unwrap_or(input),
+        false => input,
+    }
+}
+
+/// Escapes as UTF-16 code units, for languages with only `\uXXXX` escapes.
+fn escape_utf16(character: char) -> String {
+    character
+        .encode_utf16(&mut [0; 2])
+        .iter()
+        .map(|unit| format!("\\u{unit:04x}"))
+        .collect()
+}
+
+fn escape_json(character: char, escape_non_ascii: bool) -> Option<String> {
+    let escaped = match character {
+        '"' => r#"\""#,
+        '\\' => r"\\",
+        '\n' => r"\n",
+        '\r' => r"\r",
+        '\t' => r"\t",
+        '\u{8}' => r"\b",
+        '\u{c}' => r"\f",
+        _ if character.is_ascii_control() || (escape_non_ascii && !character.is_ascii()) => {
+            return Some(escape_utf16(character));
+        }
+        _ => return None,
+    };
+    Some(escaped.to_string())
+}
+
+fn escape_rust(character: char, escape_non_ascii: bool) -> Option<String> {
+    let escaped = match character {
+        '"' => r#"\""#,
+        '\\' => r"\\",
+        '\n' => r"\n",
+        '\r' => r"\r",
+        '\t' => r"\t",
+        '\0' => r"\0",
+        _ if character.is_control() || (escape_non_ascii && !character.is_ascii()) => {
+            return Some(format!("\\u{{{:x}}}", u32::from(character)));
+        }
+        _ => return None,
+    };
+    Some(escaped.to_string())
+}
+
+/// Java translates `\uXXXX` escapes before tokenizing, so `\u000a` would end the literal.
+/// ASCII control characters therefore use three-digit octal escapes instead.
+fn escape_java(character: char, escape_non_ascii: bool) -> Option<String> {
+    let escaped = match character {
+        '"' => r#"\""#,
+        '\\' => r"\\",
+        '\n' => r"\n",
+        '\r' => r"\r",
+        '\t' => r"\t",
+        '\u{8}' => r"\b",
+        '\u{c}' => r"\f",
+        _ if character.is_ascii_control() => {
+            return Some(format!("\\{:03o}", u32::from(character)));
+        }
+        _ if escape_non_ascii && !character.is_ascii() => return Some(escape_utf16(character)),
+        _ => return None,
+    };
+    Some(escaped.to_string())
+}
+
+/// Octal escapes are used for raw bytes because `\x` consumes every following hex digit.
+/// `?` is escaped after another `?` so the literal cannot form a trigraph.
+fn escape_c(input: &str, escape_non_ascii: bool) -> String {
+    let mut escaped = String::from("\"");
+    let mut previous = None;
+    for character in input.chars() {
+        match character {
+            '"' => escaped.push_str(r#"\""#),
+            '\\' => escaped.push_str(r"\\"),
+            '\n' => escaped.push_str(r"\n"),
+            '\r' => escaped.push_str(r"\r"),
+            '\t' => escaped.push_str(r"\t"),
+            '\u{7}' => escaped.push_str(r"\a"),
+            '\u{8}' => escaped.push_str(r"\b"),
+            '\u{b}' => escaped.push_str(r"\v"),
+            '\u{c}' => escaped.push_str(r"\f"),
+            '?' if previous == Some('?') => escaped.push_str(r"\?"),
+            _ if character.is_ascii_control() || (escape_non_ascii && !character.is_ascii()) => {
+                for byte in character.encode_utf8(&mut [0; 4]).bytes() {
+                    escaped.push_str(&format!("\\{byte:03o}"));
+                }
+            }
+            _ => escaped.push(character),
+        }
+        previous = Some(character);
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Quotes like Python's `repr`: single quotes unless the text contains only double quotes.
+fn escape_python(input: &str, escape_non_ascii: bool) -> String {
+    let quote_character = match input.contains('\'') && !input.contains('"') {
+        true => '"',
+        false => '\'',
+    };
+    quote(input, &quote_character.to_string(), |character| {
+        let escaped = match character {
+            '\\' => r"\\",
+            '\n' => r"\n",
+            '\r' => r"\r",
+            '\t' => r"\t",
+            _ if character == quote_character => return Some(format!("\\{character}")),
+            _ if character.is_ascii_control() => {
+                return Some(format!("\\x{:02x}", u32::from(character)))
+            }
+            _ if escape_non_ascii && !character.is_ascii() => {
+                return Some(match u32::from(character) {
+                    code_point @ ..=0xFF => format!("\\x{code_point:02x}"),
+                    code_point @ ..=0xFFFF => format!("\\u{code_point:04x}"),
+                    code_point => format!("\\U{code_point:08x}"),
+                });
+            }
+            _ => return None,
+        };
+        Some(escaped.to_string())
+    })
+}
+
+/// Suggests a raw string literal with just enough `#`s, unless the text contains a carriage
+/// return, which raw strings cannot hold.
+fn rust_raw_string(input: &str) -> Option<String> {
+    if input.contains('\r') || !input.contains(['"', '\\']) {
+        return None;
+    }
+    let hashes = input
+        .split('"')
+        .skip(1)
+        .map(|after_quote| after_quote.len() - after_quote.trim_start_matches('#').len() + 1)
+        .max()
+        .unwrap_or(0);
+    let hashes = "#".repeat(hashes);
+    Some(format!("r{hashes}\"{input}\"{hashes}"))
+}
+
+/// Takes up to `max` digits in the radix.
+fn take_digits(chars: &mut Peekable<Chars>, radix: u32, max: usize) -> String {
+    let mut digits = String::new();
+    while digits.len() < max {
+        match chars.next_if(|character| character.is_digit(radix)) {
+            Some(digit) => digits.push(digit),
+            None => break,
+        }
+    }
+    digits
+}
+
+/// Parses exactly `count` hex digits.
+fn take_hex(chars: &mut Peekable<Chars>, count: usize, escape: &str) -> Result<u32, String> {
+    let digits = take_digits(chars, 16, count);
+    match digits.len() == count {
+        true => Ok(u32::from_str_radix(&digits, 16).unwrap_or_default()),
+        false => Err(format!("\\{escape} needs {count} hex digits")),
+    }
+}
+
+fn code_point(value: u32) -> Result<char, String> {
+    char::from_u32(value).ok_or_else(|| format!("U+{value:X} is not a valid code point"))
+}
+
+/// Accepts plain, byte and raw (`r#"..."#`) string literals.
+fn unescape_rust(input: &str) -> Result<String, String> {
+    let input = match input.strip_prefix('b') {
+        Some(literal) if literal.starts_with(['"', 'r']) => literal,
+        _ => input,
+    };
+    if let Some(raw) = input
+        .strip_prefix('r')
+        .filter(|raw| raw.starts_with(['"', '#']))
+    {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let delimiter = "#".repeat(hashes);
+        return raw
+            .strip_prefix(&format!("{delimiter}\""))
+            .and_then(|raw| raw.strip_suffix(&format!("\"{delimiter}")))
+            .map(str::to_string)
+            .ok_or_else(|| "Unterminated raw string".to_string());
+    }
+
+    let mut unescaped = String::new();
+    let mut chars = strip_quotes(input, "\"").chars().peekable();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('0') => unescaped.push('\0'),
+            Some(escaped @ ('\\' | '"' | '\'')) => unescaped.push(escaped),
+            Some('x') => match take_hex(&mut chars, 2, "x")? {
+                value @ ..=0x7F => unescaped.push(code_point(value)?),
+                _ => return Err("\\x escapes must be at most \\x7f".to_string()),
+            },
+            Some('u') => {
+                if chars.next() != Some('{') {
+                    return Err("\\u escapes must be written \\u{...}".to_string());
+                }
+                let mut digits = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some('_') => {}
+                        Some(character) => digits.push(character),
+                        None => return Err("Unterminated \\u{...} escape".to_string()),
+                    }
+                }
+                let value = u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| (1..=6).contains(&digits.len()))
+                    .ok_or_else(|| format!("Invalid unicode escape \\u{{{digits}}}"))?;
+                unescaped.push(code_point(value)?);
+            }
+            Some('\n') => {
+                while chars
+                    .next_if(|character| character.is_whitespace())
+                    .is_some()
+                {}
+            }
+            Some(escaped) => return Err(format!("Unknown escape \\{escaped}")),
+            None => return Err("Trailing backslash".to_string()),
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Unescapes C escapes into bytes, which must form valid UTF-8. Also accepts the GNU `\e`.
+fn unescape_c(input: &str) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+            continue;
+        }
+        let byte = match chars.next() {
+            Some('a') => 0x07,
+            Some('b') => 0x08,
+            Some('e') => 0x1B,
+            Some('f') => 0x0C,
+            Some('n') => b'\n',
+            Some('r') => b'\r',
+            Some('t') => b'\t',
+            Some('v') => 0x0B,
+            Some(escaped @ ('\\' | '\'' | '"' | '?')) => escaped as u8,
+            Some(digit @ '0'..='7') => {
+                let digits = format!("{digit}{}", take_digits(&mut chars, 8, 2));
+                u8::try_from(u32::from_str_radix(&digits, 8).unwrap_or_default())
+                    .map_err(|_| format!("Octal escape \\{digits} is out of range"))?
+            }
+            Some('x') => {
+                let digits = take_digits(&mut chars, 16, usize::MAX);
+                u8::from_str_radix(&digits, 16)
+                    .map_err(|_| format!("Invalid hex escape \\x{digits}"))?
+            }
+            Some(escape @ ('u' | 'U')) => {
+                let count = if escape == 'u' { 4 } else { 8 };
+                let character = code_point(take_hex(&mut chars, count, &escape.to_string())?)?;
+                bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+                continue;
+            }
+            Some(escaped) => return Err(format!("Unknown escape \\{escaped}")),
+            None => return Err("Trailing backslash".to_string()),
+        };
+        bytes.push(byte);
+    }
+    String::from_utf8(bytes).map_err(|_| "Escaped bytes are not valid UTF-8".to_string())
+}
+
+/// Unescapes Java escapes, combining surrogate pairs written as two `\u` escapes.
+fn unescape_java(input: &str) -> Result<String, String> {
+    let mut units: Vec<u16> = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            units.extend_from_slice(character.encode_utf16(&mut [0; 2]));
+            continue;
+        }
+        let unit = match chars.next() {
+            Some('b') => 0x08,
+            Some('t') => 0x09,
+            Some('n') => 0x0A,
+            Some('f') => 0x0C,
+            Some('r') => 0x0D,
+            Some('s') => 0x20,
+            Some(escaped @ ('\\' | '\'' | '"')) => escaped as u16,
+            Some(digit @ '0'..='7') => {
+                // Three digits are only allowed when the first is 0-3, keeping the value a byte.
+                let max = if digit <= '3' { 2 } else { 1 };
+                let digits = format!("{digit}{}", take_digits(&mut chars, 8, max));
+                u16::from_str_radix(&digits, 8).unwrap_or_default()
+            }
+            Some('u') => {
+                while chars.next_if_eq(&'u').is_some() {}
+                take_hex(&mut chars, 4, "u")? as u16
+            }
+            Some(escaped) => return Err(format!("Unknown escape \\{escaped}")),
+            None => return Err("Trailing backslash".to_string()),
+        };
+        units.push(unit);
+    }
+    String::from_utf16(&units).map_err(|_| "Unpaired surrogate in \\u escapes".to_string())
+}
+
+/// Accepts string prefixes (`r`, `b`, `u`, `f`) and single, double or triple quotes. Unknown
+/// escapes keep their backslash, as in Python.
+fn unescape_python(input: &str) -> Result<String, String> {
+    let prefix_length = input
+        .chars()
+        .take(2)
+        .take_while(|character| "rRbBuUfF".contains(*character))
+        .count();
+    let (prefix, literal) = match input[prefix_length..].starts_with(['\'', '"']) {
+        true => input.split_at(prefix_length),
+        false => ("", input),
+    };
+    let content = ["'''", "\"\"\"", "'", "\""]
+        .iter()
+        .map(|quote| strip_quotes(literal, quote))
+        .find(|content| content.len() != literal.len())
+        .unwrap_or(literal);
+    if prefix.contains(['r', 'R']) {
+        return Ok(content.to_string());
+    }
+
+    let mut unescaped = String::new();
+    let mut chars = content.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match chars.next() {
+            Some('\n') => {}
+            Some('a') => unescaped.push('\u{7}'),
+            Some('b') => unescaped.push('\u{8}'),
+            Some('f') => unescaped.push('\u{c}'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('v') => unescaped.push('\u{b}'),
+            Some(escaped @ ('\\' | '\'' | '"')) => unescaped.push(escaped),
+            Some(digit @ '0'..='7') => {
+                let digits = format!("{digit}{}", take_digits(&mut chars, 8, 2));
+                unescaped.push(code_point(
+                    u32::from_str_radix(&digits, 8).unwrap_or_default(),
+                )?);
+            }
+            Some('x') => unescaped.push(code_point(take_hex(&mut chars, 2, "x")?)?),
+            Some('u') => unescaped.push(code_point(take_hex(&mut chars, 4, "u")?)?),
+            Some('U') => unescaped.push(code_point(take_hex(&mut chars, 8, "U")?)?),
+            Some('N') => return Err("\\N{...} named escapes are not supported".to_string()),
+            Some(escaped) => {
+                unescaped.push('\\');
+                unescaped.push(escaped);
+            }
+            None => return Err("Trailing backslash".to_string()),
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Removes POSIX shell quoting from a single word: single quotes, double quotes, backslashes
+/// and bash's ANSI-C `$'...'` quotes.
+fn unescape_shell(input: &str) -> Result<String, String> {
+    let mut unescaped = String::new();
+    let mut chars = input.chars().peekable();
+    while let Some(character) = chars.next() {
+        match character {
+            '\'' => {
+                let mut closed = false;
+                for character in chars.by_ref() {
+                    if character == '\'' {
+                        closed = true;
+                        break;
+                    }
+                    unescaped.push(character);
+                }
+                if !closed {
+                    return Err("Unterminated single quote".to_string());
+                }
+            }
+            '"' => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some('\n') => {}
+                        Some(escaped @ ('$' | '`' | '"' | '\\')) => unescaped.push(escaped),
+                        Some(other) => {
+                            unescaped.push('\\');
+                            unescaped.push(other);
+                        }
+                        None => return Err("Unterminated double quote".to_string()),
+                    },
+                    Some(other) => unescaped.push(other),
+                    None => return Err("Unterminated double quote".to_string()),
+                }
+            },
+            '$' if chars.next_if_eq(&'\'').is_some() => {
+                let mut quoted = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some('\\') => {
+                            quoted.push('\\');
+                            quoted.extend(chars.next());
+                        }
+                        Some(other) => quoted.push(other),
+                        None => return Err("Unterminated $'...' quote".to_string()),
+                    }
+                }
+                unescaped.push_str(&unescape_c(&quoted)?);
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(escaped) => unescaped.push(escaped),
+                None => return Err("Trailing backslash".to_string()),
+            },
+            _ => unescaped.push(character),
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Unescapes a regular expression that matches a literal string, rejecting escapes such as
+/// `\d` that match a class of characters.
+fn unescape_regex(input: &str) -> Result<String, String> {
+    let mut unescaped = String::new();
+    let mut chars = input.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character != '\\' {
+            if REGEX_META_CHARACTERS.contains(character) && !"#&-~".contains(character) {
+                return Err(format!("Unescaped metacharacter {character}"));
+            }
+            unescaped.push(character);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('x') if chars.next_if_eq(&'{').is_some() => {
+                let digits = take_digits(&mut chars, 16, 8);
+                if chars.next() != Some('}') {
+                    return Err("Unterminated \\x{...} escape".to_string());
+                }
+                unescaped.push(code_point(
+                    u32::from_str_radix(&digits, 16).unwrap_or_default(),
+                )?);
+            }
+            Some('x') => unescaped.push(code_point(take_hex(&mut chars, 2, "x")?)?),
+            Some(escaped) if escaped.is_ascii_punctuation() || escaped == ' ' => {
+                unescaped.push(escaped)
+            }
+            Some(escaped) => return Err(format!("\\{escaped} does not match a literal character")),
+            None => return Err("Trailing backslash".to_string()),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    const SAMPLES: [&str; 12] = [
+        "",
+        "plain text",
+        "quotes \" ' ` and \"\"double\"\"",
+        "back\\slash \\n \\\\ \\u{41}",
+        "new\nline\r\ncarriage\ttab",
+        "\0\u{1}\u{7}\u{8}\u{b}\u{c}\u{1b}\u{7f}",
+        "unicode é ß 漢字 😀 \u{200b}\u{feff}",
+        "$HOME `date` $(whoami) !history",
+        "'single' ''doubled'' '",
+        "regex .*+?()[]{}^$|#&-~ chars",
+        "trigraph ??= ??/ ??? 012",
+        "\\x41 \\101 1\u{1}2 \u{1}7",
+    ];
+
+    #[test]
+    fn round_trips_every_language() {
+        for language in StringLanguage::iter() {
+            for escape_non_ascii in [false, true] {
+                for sample in SAMPLES {
+                    let escaped = language.escape(sample, escape_non_ascii);
+                    assert_eq!(
+                        language.unescape(&escaped).as_deref(),
+                        Ok(sample),
+                        "{language} escaped {sample:?} as {escaped}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn escapes_non_ascii_only_when_enabled() {
+        for language in StringLanguage::iter().filter(StringLanguage::supports_non_ascii_escapes) {
+            assert!(language.escape("é😀", false).contains('é'));
+            assert!(language.escape("é😀", true).is_ascii(), "{language}");
+        }
+    }
+
+    #[test]
+    fn escapes_known_literals() {
+        let escape = |language: StringLanguage, input| language.escape(input, false);
+        assert_eq!(escape(StringLanguage::Json, "a\"\u{1}"), r#""a\"\u0001""#);
+        assert_eq!(escape(StringLanguage::Rust, "a\u{1}"), r#""a\u{1}""#);
+        assert_eq!(escape(StringLanguage::C, "\u{1}7??="), r#""\0017?\?=""#);
+        assert_eq!(escape(StringLanguage::Java, "\n\u{1}"), r#""\n\001""#);
+        assert_eq!(escape(StringLanguage::Python, "it's"), r#""it's""#);
+        assert_eq!(escape(StringLanguage::Sql, "it's"), "'it''s'");
+        assert_eq!(
+            escape(StringLanguage::ShellSingleQuoted, "it's"),
+            r"'it'\''s'"
+        );
+        assert_eq!(escape(StringLanguage::ShellDoubleQuoted, "$a"), r#""\$a""#);
+        assert_eq!(escape(StringLanguage::Regex, "1+1=2?"), r"1\+1=2\?");
+    }
+
+    #[test]
+    fn unescapes_other_literal_forms() {
+        let unescape = |language: StringLanguage, input| language.unescape(input).unwrap();
+        assert_eq!(
+            unescape(StringLanguage::Json, r"a\u00e9\ud83d\ude00"),
+            "aé😀"
+        );
+        assert_eq!(unescape(StringLanguage::Rust, r##"r#"a"b\"#"##), r#"a"b\"#);
+        assert_eq!(unescape(StringLanguage::Rust, "\"a\\\n    b\""), "ab");
+        assert_eq!(unescape(StringLanguage::C, r#""\x41\101\u00e9""#), "AAé");
+        assert_eq!(unescape(StringLanguage::Java, r"\uuu0041\s\7"), "A \u{7}");
+        assert_eq!(unescape(StringLanguage::Python, r"r'\n'"), r"\n");
+        assert_eq!(unescape(StringLanguage::Python, r#""""\d\x41""""#), r"\dA");
+        assert_eq!(
+            unescape(StringLanguage::ShellSingleQuoted, r#"a'b c'"$d"\ e$'\t'"#),
+            "ab c$d e\t"
+        );
+        assert_eq!(unescape(StringLanguage::Regex, r"a\.b\x{e9}"), "a.bé");
+        assert!(StringLanguage::Regex.unescape(r"\d+").is_err());
+        assert!(StringLanguage::C.unescape(r"\xff").is_err());
+    }
+
+    #[test]
+    fn suggests_raw_strings() {
+        for sample in SAMPLES {
+            if let Some(raw) = rust_raw_string(sample) {
+                assert_eq!(unescape_rust(&raw).as_deref(), Ok(sample), "{raw}");
+            }
+        }
+        assert_eq!(
+            rust_raw_string(r"C:\path"),
+            Some(r#"r"C:\path""#.to_string())
+        );
+        assert_eq!(
+            rust_raw_string(r##"say "#hi""##),
+            Some(r###"r##"say "#hi""##"###.to_string())
+        );
+        assert_eq!(rust_raw_string("plain"), None);
+        assert_eq!(rust_raw_string("\\\r"), None);
+    }
+}
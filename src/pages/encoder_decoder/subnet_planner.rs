@@ -0,0 +1,457 @@
+#![allow(non_snake_case)]
+use std::{net::IpAddr, str::FromStr};
+
+use base64ct::{Base64, Encoding};
+use cidr::{Family, IpCidr, IpInet};
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaSitemap;
+use serde::Serialize;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::{
+    components::inputs::{NumberInput, SelectForm, SelectFormEnum, TextAreaForm, TextInput},
+    pages::{
+        encoder_decoder::cidr_decoder::{
+            address_count, address_from_u128, address_to_u128, host_bits,
+        },
+        WidgetEntry, WidgetIcon,
+    },
+    utils::add_number_delimiters,
+};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Subnet Planner",
+    short_title: "Subnets",
+    description: "Split a CIDR block into equal subnets or plan subnets by host count (VLSM)",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaSitemap> = WidgetIcon { icon: FaSitemap };
+
+/// Rendering thousands of table rows gets slow, so larger plans are rejected.
+const MAX_SUBNETS: u32 = 4096;
+
+pub fn SubnetPlanner() -> Element {
+    let mut network_input = use_signal(|| "10.0.0.0/16".to_string());
+    let mut plan_mode = use_signal(PlanMode::default);
+    let mut subnet_count = use_signal(|| 4u32);
+    let mut host_requirements =
+        use_signal(|| "sales 500\nengineering 120\nguests 60\nservers 25\nlink 2".to_string());
+    let mut export_format = use_signal(ExportFormat::default);
+
+    let plan = parse_network(&network_input.read()).and_then(|network| match plan_mode() {
+        PlanMode::Equal => split_equal(network, subnet_count()),
+        PlanMode::Vlsm => parse_requirements(&host_requirements.read())
+            .and_then(|requirements| plan_vlsm(network, &requirements)),
+    });
+    let is_ipv4 = plan
+        .as_ref()
+        .ok()
+        .and_then(|plan| plan.subnets.first())
+        .is_none_or(|subnet| subnet.cidr.is_ipv4());
+    let export = plan
+        .as_ref()
+        .map(|plan| export_format().export(&plan.subnets))
+        .unwrap_or_default();
+
+    rsx! {
+        div { class: "widget subnet-planner",
+            div { class: "widget-params",
+                TextInput {
+                    label: "Network",
+                    value: "{network_input}",
+                    oninput: move |event: Event<FormData>| network_input.set(event.value()),
+                }
+                SelectForm::<PlanMode> {
+                    label: "Plan",
+                    oninput: move |mode: PlanMode| plan_mode.set(mode),
+                    value: plan_mode(),
+                }
+                if plan_mode() == PlanMode::Equal {
+                    NumberInput::<u32> {
+                        label: "Subnets",
+                        value: subnet_count(),
+                        onchange: move |count: u32| subnet_count.set(count.clamp(1, MAX_SUBNETS)),
+                    }
+                }
+            }
+            if plan_mode() == PlanMode::Vlsm {
+                TextAreaForm {
+                    label: "Host Requirements (name and host count per line)",
+                    value: "{host_requirements}",
+                    oninput: move |event: Event<FormData>| host_requirements.set(event.value()),
+                }
+            }
+            if let Ok(plan) = &plan {
+                div { class: "widget-params",
+                    TextInput {
+                        label: "Subnets",
+                        value: "{plan.subnets.len()}",
+                        readonly: true,
+                    }
+                    TextInput {
+                        label: "Unallocated Addresses",
+                        value: "{plan.unallocated}",
+                        readonly: true,
+                    }
+                }
+                div { class: "subnet-table-wrapper",
+                    table { class: "table table-sm subnet-table",
+                        thead {
+                            tr {
+                                th { "Name" }
+                                th { "Subnet" }
+                                if is_ipv4 {
+                                    th { "Netmask" }
+                                }
+                                th { "Network" }
+                                th {
+                                    if is_ipv4 {
+                                        "Broadcast"
+                                    } else {
+                                        "Last Address"
+                                    }
+                                }
+                                th { "First Host" }
+                                th { "Last Host" }
+                                th { "Usable Hosts" }
+                                if plan_mode() == PlanMode::Vlsm {
+                                    th { "Requested" }
+                                }
+                            }
+                        }
+                        tbody {
+                            for subnet in plan.subnets.iter() {
+                                tr { key: "{subnet.cidr}",
+                                    td { "{subnet.name}" }
+                                    td { "{subnet.cidr:#}" }
+                                    if is_ipv4 {
+                                        td { "{subnet.cidr.mask()}" }
+                                    }
+                                    td { "{subnet.cidr.first_address()}" }
+                                    td { "{subnet.cidr.last_address()}" }
+                                    td { "{subnet.first_host()}" }
+                                    td { "{subnet.last_host()}" }
+                                    td { "{add_number_delimiters(subnet.usable_hosts(), ',', 3)}" }
+                                    if let Some(requested) = subnet.requested_hosts {
+                                        td { "{add_number_delimiters(requested.to_string(), ',', 3)}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Err(error) = &plan {
+                div { class: "alert alert-warning m-0", "{error}" }
+            }
+            div { class: "widget-params",
+                SelectForm::<ExportFormat> {
+                    label: "Export Format",
+                    oninput: move |format: ExportFormat| export_format.set(format),
+                    value: export_format(),
+                }
+            }
+            TextAreaForm { label: "Export", value: "{export}", readonly: true }
+            if !export.is_empty() {
+                div { class: "widget-buttons",
+                    a {
+                        class: "btn btn-info",
+                        download: "subnets.{export_format().extension()}",
+                        href: "data:{export_format().mime_type()};base64,{Base64::encode_string(export.as_bytes())}",
+                        "Save Export"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum PlanMode {
+    #[default]
+    #[strum(to_string = "Equal Subnets")]
+    Equal,
+    #[strum(to_string = "VLSM (by host count)")]
+    Vlsm,
+}
+
+impl SelectFormEnum for PlanMode {}
+
+impl From<PlanMode> for String {
+    fn from(mode: PlanMode) -> Self {
+        mode.to_string()
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum ExportFormat {
+    #[default]
+    #[strum(to_string = "CSV")]
+    Csv,
+    #[strum(to_string = "Terraform JSON")]
+    TerraformJson,
+}
+
+impl SelectFormEnum for ExportFormat {}
+
+impl From<ExportFormat> for String {
+    fn from(format: ExportFormat) -> Self {
+        format.to_string()
+    }
+}
+
+impl ExportFormat {
+    fn export(&self, subnets: &[Subnet]) -> String {
+        match self {
+            Self::Csv => {
+                let mut csv = String::from(
+                    "name,cidr_block,netmask,network,last_address,first_host,last_host,usable_hosts,requested_hosts\n",
+                );
+                for subnet in subnets {
+                    csv.push_str(&format!(
+                        "{},{:#},{},{},{},{},{},{},{}\n",
+                        csv_field(&subnet.name),
+                        subnet.cidr,
+                        subnet.cidr.mask(),
+                        subnet.cidr.first_address(),
+                        subnet.cidr.last_address(),
+                        subnet.first_host(),
+                        subnet.last_host(),
+                        subnet.usable_hosts(),
+                        subnet
+                            .requested_hosts
+                            .map(|hosts| hosts.to_string())
+                            .unwrap_or_default(),
+                    ));
+                }
+                csv
+            }
+            // A list keeps the plan order; use it with
+            // `for_each = { for subnet in jsondecode(...) : subnet.name => subnet }`.
+            Self::TerraformJson => {
+                let subnets: Vec<TerraformSubnet> = subnets
+                    .iter()
+                    .map(|subnet| TerraformSubnet {
+                        name: subnet.name.clone(),
+                        cidr_block: format!("{:#}", subnet.cidr),
+                        first_host: subnet.first_host().to_string(),
+                        last_host: subnet.last_host().to_string(),
+                        usable_hosts: subnet.usable_hosts(),
+                        requested_hosts: subnet.requested_hosts.map(|hosts| hosts.to_string()),
+                    })
+                    .collect();
+                serde_json::to_string_pretty(&subnets).unwrap_or_default()
+            }
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::TerraformJson => "json",
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::TerraformJson => "application/json",
+        }
+    }
+}
+
+/// Host counts are strings because IPv6 counts do not fit in a JSON number.
+#[derive(Serialize)]
+struct TerraformSubnet {
+    name: String,
+    cidr_block: String,
+    first_host: String,
+    last_host: String,
+    usable_hosts: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requested_hosts: Option<String>,
+}
+
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+struct SubnetPlan {
+    subnets: Vec<Subnet>,
+    unallocated: String,
+}
+
+struct Subnet {
+    name: String,
+    cidr: IpCidr,
+    requested_hosts: Option<u128>,
+}
+
+impl Subnet {
+    /// IPv4 networks lose their network and broadcast addresses except for /31 point-to-point
+    /// links (RFC 3021) and /32 hosts. IPv6 has no broadcast address, so every address counts.
+    fn has_reserved_addresses(&self) -> bool {
+        self.cidr.is_ipv4() && self.cidr.network_length() < 31
+    }
+
+    fn first_host(&self) -> IpAddr {
+        let first = address_to_u128(self.cidr.first_address());
+        match self.has_reserved_addresses() {
+            true => address_from_u128(first + 1, &self.cidr.family()),
+            false => self.cidr.first_address(),
+        }
+    }
+
+    fn last_host(&self) -> IpAddr {
+        let last = address_to_u128(self.cidr.last_address());
+        match self.has_reserved_addresses() {
+            true => address_from_u128(last - 1, &self.cidr.family()),
+            false => self.cidr.last_address(),
+        }
+    }
+
+    fn usable_hosts(&self) -> String {
+        match host_bits(&self.cidr) {
+            128 => address_count(128).to_string(),
+            bits => usable_hosts(&self.cidr.family(), bits).to_string(),
+        }
+    }
+}
+
+/// Accepts a CIDR, an address with a prefix length that has host bits set, or a bare address.
+fn parse_network(input: &str) -> Result<IpCidr, String> {
+    IpInet::from_str(input.trim())
+        .map(|inet| inet.network())
+        .map_err(|_| "The provided network is not a valid CIDR.".to_string())
+}
+
+/// Saturates at `u128::MAX` for ::/0, which is enough to compare against host counts.
+fn usable_hosts(family: &Family, host_bits: u32) -> u128 {
+    let addresses = 1u128.checked_shl(host_bits).unwrap_or(u128::MAX);
+    match family {
+        Family::Ipv4 if host_bits >= 2 => addresses - 2,
+        _ => addresses,
+    }
+}
+
+/// Splits a network into the smallest power of two of equal subnets that holds `count`,
+/// listing only the first `count` of them.
+fn split_equal(network: IpCidr, count: u32) -> Result<SubnetPlan, String> {
+    let extra_bits = count.max(1).next_power_of_two().trailing_zeros();
+    let available_bits = host_bits(&network);
+    if extra_bits > available_bits {
+        return Err(format!(
+            "A /{} cannot be split into {count} subnets.",
+            network.network_length()
+        ));
+    }
+    let subnet_bits = available_bits - extra_bits;
+    let length = network.family().len() - subnet_bits as u8;
+    let first = address_to_u128(network.first_address());
+    let subnets = (0..u128::from(count))
+        .map(|index| {
+            let address = first + index.checked_shl(subnet_bits).unwrap_or(0);
+            Subnet {
+                name: format!("subnet-{}", index + 1),
+                cidr: IpCidr::new(address_from_u128(address, &network.family()), length)
+                    .expect("subnet addresses are aligned to the prefix length"),
+                requested_hosts: None,
+            }
+        })
+        .collect();
+    // At most 2^extra_bits - 1 subnets are unused, so this stays below 2^available_bits.
+    let unused_subnets = (1u128 << extra_bits) - u128::from(count);
+    let unallocated = match unused_subnets {
+        0 => 0,
+        unused_subnets => unused_subnets << subnet_bits,
+    };
+    Ok(SubnetPlan {
+        subnets,
+        unallocated: add_number_delimiters(unallocated.to_string(), ',', 3),
+    })
+}
+
+/// Allocates the largest requirements first so that every subnet stays aligned.
+fn plan_vlsm(network: IpCidr, requirements: &[(String, u128)]) -> Result<SubnetPlan, String> {
+    if requirements.len() > MAX_SUBNETS as usize {
+        return Err(format!("Plans are limited to {MAX_SUBNETS} subnets."));
+    }
+    let family = network.family();
+    let mut sorted: Vec<&(String, u128)> = requirements.iter().collect();
+    sorted.sort_by_key(|(_, hosts)| std::cmp::Reverse(*hosts));
+
+    let last = address_to_u128(network.last_address());
+    let mut next = Some(address_to_u128(network.first_address()));
+    let mut subnets = Vec::new();
+    for (name, hosts) in sorted {
+        let no_room = || format!("{network:#} has no room left for \"{name}\" ({hosts} hosts).");
+        let bits = (0..=host_bits(&network))
+            .find(|bits| usable_hosts(&family, *bits) >= *hosts)
+            .ok_or_else(no_room)?;
+        let block_mask = 1u128.checked_shl(bits).map_or(u128::MAX, |size| size - 1);
+        let address = next
+            .filter(|address| address | block_mask <= last)
+            .ok_or_else(no_room)?;
+        subnets.push(Subnet {
+            name: name.clone(),
+            cidr: IpCidr::new(
+                address_from_u128(address, &family),
+                family.len() - bits as u8,
+            )
+            .expect("subnet addresses are aligned to the prefix length"),
+            requested_hosts: Some(*hosts),
+        });
+        next = (address | block_mask).checked_add(1);
+    }
+    // Only an empty plan for ::/0 leaves all 2^128 addresses unallocated.
+    let unallocated = match next {
+        Some(address) if address <= last => match (last - address).checked_add(1) {
+            Some(count) => add_number_delimiters(count.to_string(), ',', 3),
+            None => add_number_delimiters(address_count(128).to_string(), ',', 3),
+        },
+        _ => "0".to_string(),
+    };
+    Ok(SubnetPlan {
+        subnets,
+        unallocated,
+    })
+}
+
+/// Parses lines like `web 120`, `web: 120`, `web,120` or just `120`.
+fn parse_requirements(input: &str) -> Result<Vec<(String, u128)>, String> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            let (name, hosts) = match line.rfind([' ', '\t', ':', ',', '=']) {
+                Some(position) => (
+                    line[..position]
+                        .trim()
+                        .trim_end_matches([':', ',', '='])
+                        .trim(),
+                    line[position + 1..].trim(),
+                ),
+                None => ("", line),
+            };
+            let hosts = hosts
+                .replace('_', "")
+                .parse::<u128>()
+                .map_err(|_| format!("Line {}: \"{hosts}\" is not a host count.", index + 1))?;
+            let name = match name.is_empty() {
+                true => format!("subnet-{}", index + 1),
+                false => name.to_string(),
+            };
+            Ok((name, hosts))
+        })
+        .collect()
+}
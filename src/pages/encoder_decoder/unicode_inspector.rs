@@ -0,0 +1,315 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaFont;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::components::inputs::{SelectForm, SelectFormEnum, TextAreaForm, TextInput};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Unicode Inspector",
+    short_title: "Unicode",
+    description: "Inspect and normalize the code points in a string",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaFont> = WidgetIcon { icon: FaFont };
+
+/// Rendering a row per code point gets slow for long texts, so the table stops here.
+const MAX_TABLE_CODE_POINTS: usize = 1000;
+
+pub fn UnicodeInspector() -> Element {
+    let mut text = use_signal(|| "Cafe\u{301} 👩‍💻 pаypal\u{200b}".to_string());
+    let mut normalization_form = use_signal(NormalizationForm::default);
+
+    let text_value = text.read().clone();
+    let normalized = normalization_form().normalize(&text_value);
+    let graphemes: Vec<&str> = text_value.graphemes(true).collect();
+    let code_points = text_value.chars().count();
+    let warnings = text_value
+        .chars()
+        .filter(|character| character_warning(*character).is_some())
+        .count();
+    let mut remaining_rows = MAX_TABLE_CODE_POINTS;
+    let table_graphemes: Vec<&str> = graphemes
+        .iter()
+        .copied()
+        .take_while(|grapheme| {
+            let fits = remaining_rows > 0;
+            remaining_rows = remaining_rows.saturating_sub(grapheme.chars().count());
+            fits
+        })
+        .collect();
+
+    rsx! {
+        div { class: "widget unicode-inspector",
+            TextAreaForm {
+                label: "Text",
+                value: "{text}",
+                oninput: move |event: Event<FormData>| text.set(event.value()),
+            }
+            div { class: "widget-params",
+                TextInput {
+                    label: "Graphemes",
+                    value: "{graphemes.len()}",
+                    readonly: true,
+                }
+                TextInput {
+                    label: "Code Points",
+                    value: "{code_points}",
+                    readonly: true,
+                }
+                TextInput {
+                    label: "UTF-8 Bytes",
+                    value: "{text_value.len()}",
+                    readonly: true,
+                }
+                TextInput {
+                    label: "UTF-16 Code Units",
+                    value: "{text_value.encode_utf16().count()}",
+                    readonly: true,
+                }
+            }
+            if warnings > 0 {
+                div { class: "alert alert-warning m-0",
+                    "{warnings} invisible, control or confusable code point(s) highlighted below"
+                }
+            }
+            div { class: "unicode-table-wrapper",
+                table { class: "table table-sm unicode-table",
+                    thead {
+                        tr {
+                            th { "Grapheme" }
+                            th { "Code Point" }
+                            th { "Name" }
+                            th { "Category" }
+                            th { "Block" }
+                            th { "UTF-8" }
+                            th { "UTF-16" }
+                            th { "UTF-32" }
+                            th { "Escapes" }
+                            th { "Notes" }
+                        }
+                    }
+                    tbody {
+                        for (grapheme_index , grapheme) in table_graphemes.iter().enumerate() {
+                            for (index , character) in grapheme.chars().enumerate() {
+                                CodePointRow {
+                                    key: "{grapheme_index}-{index}",
+                                    character,
+                                    grapheme: (index == 0).then(|| grapheme.to_string()),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if table_graphemes.len() < graphemes.len() {
+                div { class: "alert alert-info m-0",
+                    "Only the first {MAX_TABLE_CODE_POINTS} code points are listed"
+                }
+            }
+            div { class: "widget-params",
+                SelectForm::<NormalizationForm> {
+                    label: "Normalization Form",
+                    oninput: move |form: NormalizationForm| normalization_form.set(form),
+                    value: normalization_form(),
+                }
+                TextInput {
+                    label: "Already Normalized",
+                    value: if normalization_form().is_normalized(&text_value) { "Yes" } else { "No" },
+                    readonly: true,
+                }
+            }
+            TextAreaForm { label: "Normalized", value: "{normalized}", readonly: true }
+            div { class: "widget-buttons",
+                button {
+                    class: "btn btn-info",
+                    onclick: move |_| {
+                        let normalized = normalization_form().normalize(&text.read());
+                        text.set(normalized);
+                    },
+                    "Use as Input"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CodePointRow(character: char, grapheme: Option<String>) -> Element {
+    let code_point = u32::from(character);
+    let category = get_general_category(character);
+    let warning = character_warning(character);
+    let flagged = warning.is_some();
+    let utf8: Vec<String> = character
+        .encode_utf8(&mut [0; 4])
+        .bytes()
+        .map(|byte| format!("{byte:02X}"))
+        .collect();
+    let utf16: Vec<String> = character
+        .encode_utf16(&mut [0; 2])
+        .iter()
+        .map(|unit| format!("{unit:04X}"))
+        .collect();
+    let percent_encoded: String = utf8.iter().map(|byte| format!("%{byte}")).collect();
+    let grapheme_rows = grapheme
+        .as_ref()
+        .map(|grapheme| grapheme.chars().count())
+        .unwrap_or_default();
+
+    rsx! {
+        tr { class: if flagged { "flagged" },
+            if let Some(grapheme) = grapheme {
+                td { class: "grapheme", rowspan: "{grapheme_rows}", "{visible_grapheme(&grapheme)}" }
+            }
+            td { "U+{code_point:04X}" }
+            td { "{character_name(character, category)}" }
+            td { "{category.abbreviation()} ({category_name(category)})" }
+            td {
+                {unicode_blocks::find_unicode_block(character).map(|block| block.name()).unwrap_or("No Block")}
+            }
+            td { "{utf8.join(\" \")}" }
+            td { "{utf16.join(\" \")}" }
+            td { "{code_point:08X}" }
+            td {
+                div { "\\u{{{code_point:x}}}" }
+                div { "&#x{code_point:X};" }
+                div { "{percent_encoded}" }
+            }
+            td { {warning} }
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum NormalizationForm {
+    #[default]
+    #[strum(to_string = "NFC")]
+    Nfc,
+    #[strum(to_string = "NFD")]
+    Nfd,
+    #[strum(to_string = "NFKC")]
+    Nfkc,
+    #[strum(to_string = "NFKD")]
+    Nfkd,
+}
+
+impl SelectFormEnum for NormalizationForm {}
+
+impl From<NormalizationForm> for String {
+    fn from(form: NormalizationForm) -> Self {
+        form.to_string()
+    }
+}
+
+impl NormalizationForm {
+    fn normalize(&self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfd => text.nfd().collect(),
+            Self::Nfkc => text.nfkc().collect(),
+            Self::Nfkd => text.nfkd().collect(),
+        }
+    }
+
+    fn is_normalized(&self, text: &str) -> bool {
+        match self {
+            Self::Nfc => is_nfc(text),
+            Self::Nfd => is_nfd(text),
+            Self::Nfkc => is_nfkc(text),
+            Self::Nfkd => is_nfkd(text),
+        }
+    }
+}
+
+fn character_name(character: char, category: GeneralCategory) -> String {
+    match unicode_names2::name(character) {
+        Some(name) => name.to_string(),
+        None => match category {
+            GeneralCategory::Control => "<control>".to_string(),
+            GeneralCategory::PrivateUse => "<private use>".to_string(),
+            GeneralCategory::Unassigned => "<unassigned>".to_string(),
+            _ => "<unnamed>".to_string(),
+        },
+    }
+}
+
+/// Spells out a category variant, e.g. `LowercaseLetter` as "Lowercase Letter".
+fn category_name(category: GeneralCategory) -> String {
+    let mut name = String::new();
+    for character in format!("{category:?}").chars() {
+        if character.is_uppercase() && !name.is_empty() {
+            name.push(' ');
+        }
+        name.push(character);
+    }
+    name
+}
+
+/// Describes characters that are invisible, reorder text or impersonate other characters.
+fn character_warning(character: char) -> Option<String> {
+    match invisible_warning(character) {
+        Some(warning) => Some(warning.to_string()),
+        None if !character.is_ascii() => {
+            let skeleton: String = unicode_security::skeleton(&character.to_string()).collect();
+            let decomposed: String = character.to_string().nfd().collect();
+            (skeleton != decomposed).then(|| format!("Looks like \"{skeleton}\""))
+        }
+        None => None,
+    }
+}
+
+fn invisible_warning(character: char) -> Option<&'static str> {
+    let warning = match character {
+        '\u{61C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' => {
+            "Bidirectional control"
+        }
+        '\u{FEFF}' => "Byte order mark / zero width no-break space",
+        '\u{200D}' => "Zero width joiner",
+        '\u{200C}' => "Zero width non-joiner",
+        '\u{200B}' => "Zero width space",
+        '\u{AD}' => "Soft hyphen",
+        '\u{34F}'
+        | '\u{115F}'
+        | '\u{1160}'
+        | '\u{3164}'
+        | '\u{FFA0}'
+        | '\u{180B}'..='\u{180F}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{E0000}'..='\u{E0FFF}' => "Invisible",
+        '\t' | '\n' | '\r' | ' ' => return None,
+        _ => match get_general_category(character) {
+            GeneralCategory::Format => "Invisible formatting character",
+            GeneralCategory::Control => "Control character",
+            GeneralCategory::SpaceSeparator
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator => "Unusual whitespace",
+            _ => return None,
+        },
+    };
+    Some(warning)
+}
+
+/// Replaces graphemes that would render as nothing with a visible placeholder.
+fn visible_grapheme(grapheme: &str) -> String {
+    match grapheme.chars().next() {
+        Some(character @ '\0'..='\u{1F}') => char::from_u32(0x2400 + u32::from(character))
+            .unwrap_or('◌')
+            .to_string(),
+        Some('\u{7F}') => "␡".to_string(),
+        Some(' ') => "␠".to_string(),
+        _ if grapheme
+            .chars()
+            .all(|character| invisible_warning(character).is_some()) =>
+        {
+            "◌".to_string()
+        }
+        _ => grapheme.to_string(),
+    }
+}
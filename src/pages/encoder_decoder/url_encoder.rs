@@ -0,0 +1,173 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaLink;
+use percent_encoding::{percent_decode_str, percent_encode, AsciiSet, CONTROLS};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::components::encoder_pane::{Direction, EncoderPane, EncoderText};
+use crate::components::inputs::{SelectForm, SelectFormEnum};
+use crate::pages::{WidgetEntry, WidgetIcon};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "URL Encoder / Decoder",
+    short_title: "URL Encoder",
+    description: "Percent-encode and decode URL components",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaLink> = WidgetIcon { icon: FaLink };
+
+/// WHATWG fragment percent-encode set, plus `%` so that decoding round-trips.
+const FRAGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'%');
+
+/// WHATWG query percent-encode set, plus the characters that delimit query parameters.
+const QUERY: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'%')
+    .add(b'&')
+    .add(b'\'')
+    .add(b'+')
+    .add(b'=');
+
+/// WHATWG path percent-encode set, plus `/` and `%` so the value stays a single segment.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'%')
+    .add(b'/');
+
+pub fn UrlEncoder() -> Element {
+    let mut value_context = use_context_provider(|| {
+        Signal::new(EncoderValue {
+            text: EncoderText::default(),
+            component: UrlComponent::default(),
+        })
+    });
+    let component = value_context.read().component;
+    let is_binary = value_context.read().text.is_binary();
+
+    rsx! {
+        div { class: "widget",
+            div { class: "widget-params",
+                SelectForm::<UrlComponent> {
+                    label: "URL Component",
+                    oninput: move |component: UrlComponent| {
+                        value_context
+                            .with_mut(|value| {
+                                value.component = component;
+                                value.refresh();
+                            });
+                    },
+                    value: component,
+                }
+            }
+            EncoderPane {
+                direction: Direction::Encode,
+                value: value_context.read().text.decoded_value.clone(),
+                is_binary,
+                encoded_label: "Encoded",
+                oninput: move |input: String| {
+                    value_context
+                        .with_mut(|value| {
+                            value.text.set_decoded_bytes(input.into_bytes());
+                            value.encode();
+                        });
+                },
+            }
+            EncoderPane {
+                direction: Direction::Decode,
+                value: value_context.read().text.encoded_value.clone(),
+                is_binary,
+                encoded_label: "Encoded",
+                oninput: move |input: String| value_context.with_mut(|value| value.decode(input)),
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EncoderValue {
+    text: EncoderText,
+    component: UrlComponent,
+}
+
+impl EncoderValue {
+    fn encode(&mut self) {
+        self.text.encoded_value = self.component.encode(&self.text.decoded_bytes);
+        self.text.last_edited = Direction::Encode;
+    }
+
+    fn decode(&mut self, input: String) {
+        self.text.set_decoded_bytes(self.component.decode(&input));
+        self.text.encoded_value = input;
+        self.text.last_edited = Direction::Decode;
+    }
+
+    /// Re-encodes the text or re-decodes the encoded input, whichever was typed last, after
+    /// the component changes.
+    fn refresh(&mut self) {
+        match self.text.last_edited {
+            Direction::Encode => self.encode(),
+            Direction::Decode => self.decode(self.text.encoded_value.clone()),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum UrlComponent {
+    #[default]
+    #[strum(to_string = "Query Parameter")]
+    Query,
+    #[strum(to_string = "Path Segment")]
+    PathSegment,
+    #[strum(to_string = "Fragment")]
+    Fragment,
+    #[strum(to_string = "Form (application/x-www-form-urlencoded)")]
+    Form,
+}
+
+impl SelectFormEnum for UrlComponent {}
+
+impl From<UrlComponent> for String {
+    fn from(component: UrlComponent) -> Self {
+        component.to_string()
+    }
+}
+
+impl UrlComponent {
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Query => percent_encode(bytes, QUERY).to_string(),
+            Self::PathSegment => percent_encode(bytes, PATH_SEGMENT).to_string(),
+            Self::Fragment => percent_encode(bytes, FRAGMENT).to_string(),
+            Self::Form => url::form_urlencoded::byte_serialize(bytes).collect(),
+        }
+    }
+
+    /// Decodes the percent escapes, which may spell out bytes that are not UTF-8.
+    fn decode(&self, value: &str) -> Vec<u8> {
+        match self {
+            Self::Form => percent_decode_str(&value.replace('+', " ")).collect(),
+            _ => percent_decode_str(value).collect(),
+        }
+    }
+}
@@ -0,0 +1,1521 @@
+#![allow(non_snake_case)]
+use base64ct::{Base64, Encoding};
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaBarcode;
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
+
+use crate::{
+    components::inputs::{SelectForm, SelectFormEnum, SwitchInput, TextAreaForm, TextInput},
+    pages::{WidgetEntry, WidgetIcon},
+    utils::barcode::{
+        ean_check_digit, CODE128_PATTERNS, CODE39_ALPHABET, CODE39_PATTERNS, EAN_DIGIT_PATTERNS,
+        EAN_FIRST_DIGIT_PARITY, PDF417_CODEWORD_PATTERNS,
+    },
+};
+
+pub const WIDGET_ENTRY: WidgetEntry = WidgetEntry {
+    title: "Barcode Generator",
+    short_title: "Barcode",
+    description: "Generate 1D and 2D barcodes from text",
+    icon: move || ICON.icon(),
+};
+
+const ICON: WidgetIcon<FaBarcode> = WidgetIcon { icon: FaBarcode };
+
+const PNG_SCALE: u32 = 4;
+
+pub fn BarcodeGenerator() -> Element {
+    let mut barcode_value = use_signal(|| "".to_string());
+    let mut symbology = use_signal(Symbology::default);
+    let mut human_readable = use_signal(|| true);
+    let mut code39_check_digit = use_signal(|| false);
+
+    let barcode = match barcode_value.read().is_empty() {
+        true => None,
+        false => Some(encode_barcode(
+            *symbology.read(),
+            &barcode_value.read(),
+            *code39_check_digit.read(),
+        )),
+    };
+    let svg = match &barcode {
+        Some(Ok(barcode)) => Some(barcode.symbol.to_svg(*human_readable.read())),
+        _ => None,
+    };
+    let svg_src = svg.as_ref().map(|svg| {
+        format!(
+            "data:image/svg+xml;base64,{}",
+            Base64::encode_string(svg.as_bytes())
+        )
+    });
+    let file_name = format!("{}", symbology.read())
+        .to_lowercase()
+        .replace(' ', "-");
+
+    rsx! {
+        div { class: "widget barcode-generator",
+            div { class: "widget-params",
+                SelectForm::<Symbology> {
+                    label: "Symbology",
+                    oninput: move |new_symbology: Symbology| {
+                        symbology.set(new_symbology);
+                    },
+                    value: *symbology.read(),
+                }
+            }
+            div { class: "widget-switches",
+                if symbology.read().is_linear() {
+                    SwitchInput {
+                        label: "Human-readable text",
+                        checked: *human_readable.read(),
+                        oninput: move |is_enabled| {
+                            human_readable.set(is_enabled);
+                        },
+                    }
+                }
+                if *symbology.read() == Symbology::Code39 {
+                    SwitchInput {
+                        label: "Mod 43 check digit",
+                        checked: *code39_check_digit.read(),
+                        oninput: move |is_enabled| {
+                            code39_check_digit.set(is_enabled);
+                        },
+                    }
+                }
+            }
+            TextAreaForm {
+                label: "Input",
+                value: barcode_value,
+                oninput: move |event: Event<FormData>| {
+                    barcode_value.set(event.value());
+                },
+            }
+            if let Some(Err(err)) = &barcode {
+                div { class: "alert alert-warning m-0", "{err}" }
+            }
+            if let Some(Ok(EncodedBarcode { check_digit: Some(check_digit), .. })) = &barcode {
+                TextInput {
+                    label: "Check Digit",
+                    value: "{check_digit}",
+                    readonly: true,
+                }
+            }
+            if let (Some(svg), Some(svg_src)) = (svg, svg_src) {
+                img { class: "barcode", src: "{svg_src}" }
+                div { class: "widget-buttons",
+                    a {
+                        class: "btn btn-info",
+                        download: "{file_name}.svg",
+                        href: "{svg_src}",
+                        "Download SVG"
+                    }
+                    button {
+                        class: "btn btn-info",
+                        onclick: move |_| {
+                            download_png(svg.clone(), format!("{file_name}.png"));
+                        },
+                        "Download PNG"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rasterizes the SVG in the webview, so the human-readable text is rendered with the same font
+/// as the preview, and saves the result as a PNG.
+fn download_png(svg: String, file_name: String) {
+    let eval = document::eval(&format!(
+        r#"
+        let [svg, fileName] = await dioxus.recv();
+        let image = new Image();
+        image.onload = () => {{
+            let canvas = document.createElement("canvas");
+            canvas.width = image.width * {PNG_SCALE};
+            canvas.height = image.height * {PNG_SCALE};
+            let context = canvas.getContext("2d");
+            context.imageSmoothingEnabled = false;
+            context.drawImage(image, 0, 0, canvas.width, canvas.height);
+            let link = document.createElement("a");
+            link.href = canvas.toDataURL("image/png");
+            link.download = fileName;
+            link.click();
+        }};
+        image.src = "data:image/svg+xml;charset=utf-8," + encodeURIComponent(svg);
+        "#
+    ));
+    if let Err(err) = eval.send((svg, file_name)) {
+        tracing::error!("failed to export barcode as PNG: {err:?}");
+    }
+}
+
+#[derive(
+    Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
+)]
+enum Symbology {
+    #[default]
+    #[strum(to_string = "Code 128")]
+    Code128,
+    #[strum(to_string = "EAN-13")]
+    Ean13,
+    #[strum(to_string = "UPC-A")]
+    UpcA,
+    #[strum(to_string = "Code 39")]
+    Code39,
+    #[strum(to_string = "Data Matrix")]
+    DataMatrix,
+    #[strum(to_string = "Aztec")]
+    Aztec,
+    #[strum(to_string = "PDF417")]
+    Pdf417,
+}
+
+impl SelectFormEnum for Symbology {}
+
+impl From<Symbology> for String {
+    fn from(symbology: Symbology) -> Self {
+        symbology.to_string()
+    }
+}
+
+impl Symbology {
+    fn is_linear(&self) -> bool {
+        !matches!(self, Self::DataMatrix | Self::Aztec | Self::Pdf417)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct EncodedBarcode {
+    symbol: Symbol,
+    check_digit: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Symbol {
+    Linear(LinearSymbol),
+    Matrix(Vec<Vec<bool>>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct LinearSymbol {
+    modules: Vec<bool>,
+    /// Indices of modules whose bars extend into the text area (EAN/UPC guard bars).
+    guards: Vec<usize>,
+    /// Human-readable text as (center in modules, text) pairs, relative to the first module.
+    labels: Vec<(f32, String)>,
+    quiet_zone: usize,
+}
+
+impl LinearSymbol {
+    fn push_widths(&mut self, widths: &[u8], starts_dark: bool) {
+        for (i, width) in widths.iter().enumerate() {
+            let dark = (i % 2 == 0) == starts_dark;
+            self.modules
+                .extend(std::iter::repeat_n(dark, usize::from(*width)));
+        }
+    }
+
+    fn push_guard(&mut self, widths: &[u8], starts_dark: bool) {
+        let start = self.modules.len();
+        self.push_widths(widths, starts_dark);
+        self.guards.extend(start..self.modules.len());
+    }
+}
+
+const BAR_HEIGHT: usize = 50;
+const GUARD_EXTENSION: usize = 5;
+const TEXT_HEIGHT: usize = 10;
+const SVG_SCALE: usize = 2;
+
+impl Symbol {
+    fn to_svg(&self, human_readable: bool) -> String {
+        let (width, height, path, text) = match self {
+            Symbol::Linear(symbol) => {
+                let show_text = human_readable && !symbol.labels.is_empty();
+                let width = symbol.modules.len() + 2 * symbol.quiet_zone;
+                let height = BAR_HEIGHT + if show_text { TEXT_HEIGHT + 2 } else { 0 };
+                let mut path = String::new();
+                for (x, dark) in symbol.modules.iter().enumerate() {
+                    if *dark {
+                        let bar_height = match show_text && symbol.guards.contains(&x) {
+                            true => BAR_HEIGHT + GUARD_EXTENSION,
+                            false => BAR_HEIGHT,
+                        };
+                        path.push_str(&format!("M{},0h1v{bar_height}h-1z", x + symbol.quiet_zone));
+                    }
+                }
+                let text = match show_text {
+                    true => symbol
+                        .labels
+                        .iter()
+                        .map(|(center, label)| {
+                            format!(
+                                r#"<text x="{}" y="{}" font-family="monospace" font-size="{TEXT_HEIGHT}" text-anchor="middle">{}</text>"#,
+                                center + symbol.quiet_zone as f32,
+                                BAR_HEIGHT + TEXT_HEIGHT,
+                                escape_xml(label)
+                            )
+                        })
+                        .collect(),
+                    false => String::new(),
+                };
+                (width, height, path, text)
+            }
+            Symbol::Matrix(matrix) => {
+                let quiet_zone = 2;
+                let size = matrix.len() + 2 * quiet_zone;
+                let width = matrix.first().map_or(0, Vec::len) + 2 * quiet_zone;
+                let mut path = String::new();
+                for (y, row) in matrix.iter().enumerate() {
+                    for (x, dark) in row.iter().enumerate() {
+                        if *dark {
+                            path.push_str(&format!(
+                                "M{},{}h1v1h-1z",
+                                x + quiet_zone,
+                                y + quiet_zone
+                            ));
+                        }
+                    }
+                }
+                (width, size, path, String::new())
+            }
+        };
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges"><rect width="100%" height="100%" fill="white"/><path fill="black" d="{path}"/>{text}</svg>"#,
+            width * SVG_SCALE,
+            height * SVG_SCALE,
+        )
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn encode_barcode(
+    symbology: Symbology,
+    input: &str,
+    code39_check_digit: bool,
+) -> Result<EncodedBarcode, String> {
+    match symbology {
+        Symbology::Code128 => encode_code128(input),
+        Symbology::Ean13 => encode_ean13(input, 13),
+        Symbology::UpcA => encode_ean13(input, 12),
+        Symbology::Code39 => encode_code39(input, code39_check_digit),
+        Symbology::DataMatrix => Ok(EncodedBarcode {
+            symbol: Symbol::Matrix(encode_data_matrix(input.as_bytes())?),
+            check_digit: None,
+        }),
+        Symbology::Aztec => Ok(EncodedBarcode {
+            symbol: Symbol::Matrix(encode_aztec(input.as_bytes())?),
+            check_digit: None,
+        }),
+        Symbology::Pdf417 => Ok(EncodedBarcode {
+            symbol: Symbol::Matrix(encode_pdf417(input.as_bytes())?),
+            check_digit: None,
+        }),
+    }
+}
+
+// EAN-13 / UPC-A
+
+/// Encodes EAN-13 (`length` 13) or UPC-A (`length` 12). The check digit is computed when it is
+/// omitted and validated when it is given.
+fn encode_ean13(input: &str, length: usize) -> Result<EncodedBarcode, String> {
+    let input = input.trim();
+    let mut digits = input
+        .chars()
+        .map(|character| character.to_digit(10).map(|digit| digit as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or("Only digits are allowed.")?;
+    let check_digit = match digits.len() {
+        len if len == length - 1 => {
+            let check_digit = ean_check_digit(&digits);
+            digits.push(check_digit);
+            check_digit
+        }
+        len if len == length => {
+            let expected = ean_check_digit(&digits[..length - 1]);
+            if digits[length - 1] != expected {
+                return Err(format!(
+                    "Invalid check digit {}, expected {expected}.",
+                    digits[length - 1]
+                ));
+            }
+            expected
+        }
+        _ => {
+            return Err(format!(
+                "Expected {} digits, or {length} digits including the check digit.",
+                length - 1
+            ))
+        }
+    };
+    if length == 12 {
+        digits.insert(0, 0);
+    }
+
+    let mut symbol = LinearSymbol {
+        quiet_zone: 11,
+        ..Default::default()
+    };
+    symbol.push_guard(&[1, 1, 1], true);
+    let parity = EAN_FIRST_DIGIT_PARITY[usize::from(digits[0])];
+    for (i, digit) in digits[1..7].iter().enumerate() {
+        let widths = EAN_DIGIT_PATTERNS[usize::from(*digit)];
+        match parity & (1 << (5 - i)) != 0 {
+            true => symbol.push_widths(&[widths[3], widths[2], widths[1], widths[0]], false),
+            false => symbol.push_widths(&widths, false),
+        }
+    }
+    symbol.push_guard(&[1, 1, 1, 1, 1], false);
+    for digit in &digits[7..] {
+        symbol.push_widths(&EAN_DIGIT_PATTERNS[usize::from(*digit)], true);
+    }
+    symbol.push_guard(&[1, 1, 1], true);
+
+    let text: String = digits
+        .iter()
+        .map(|digit| char::from(b'0' + digit))
+        .collect();
+    symbol.labels = match length {
+        12 => {
+            // UPC-A prints the number system and check digits outside the bars and extends the
+            // bars of those digits like guards.
+            symbol.guards.extend(3..10);
+            symbol.guards.extend(85..92);
+            vec![
+                (-5.0, text[1..2].to_string()),
+                (27.5, text[2..7].to_string()),
+                (67.5, text[7..12].to_string()),
+                (100.0, text[12..].to_string()),
+            ]
+        }
+        _ => vec![
+            (-5.0, text[..1].to_string()),
+            (24.0, text[1..7].to_string()),
+            (71.0, text[7..].to_string()),
+        ],
+    };
+
+    Ok(EncodedBarcode {
+        symbol: Symbol::Linear(symbol),
+        check_digit: Some(check_digit.to_string()),
+    })
+}
+
+// Code 128
+
+const CODE128_SHIFT_A: u8 = 101;
+const CODE128_SHIFT_B: u8 = 100;
+const CODE128_SHIFT_C: u8 = 99;
+const CODE128_START_A: u8 = 103;
+const CODE128_START_B: u8 = 104;
+const CODE128_START_C: u8 = 105;
+const CODE128_STOP: usize = 106;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum CodeSet {
+    A,
+    B,
+    C,
+}
+
+fn encode_code128(input: &str) -> Result<EncodedBarcode, String> {
+    if !input.is_ascii() {
+        return Err("Code 128 only supports ASCII characters.".to_string());
+    }
+    let bytes = input.as_bytes();
+    let digit_run = |start: usize| {
+        bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let mut values: Vec<u8> = vec![];
+    let mut code_set = None;
+    let mut position = 0;
+    while position < bytes.len() {
+        let run = digit_run(position);
+        let at_edge = position == 0 || position + run == bytes.len();
+        let use_c = match code_set {
+            Some(CodeSet::C) => run >= 2,
+            _ => run >= if at_edge { 4 } else { 6 },
+        };
+        if use_c {
+            if run % 2 == 1 && code_set != Some(CodeSet::C) {
+                // Encode the odd digit first so the rest of the run fits in pairs.
+                let current = code_set.unwrap_or(CodeSet::B);
+                push_code128_code_set(&mut values, &mut code_set, current);
+                values.push(bytes[position] - 32);
+                position += 1;
+            }
+            push_code128_code_set(&mut values, &mut code_set, CodeSet::C);
+            while position + 1 < bytes.len()
+                && bytes[position].is_ascii_digit()
+                && bytes[position + 1].is_ascii_digit()
+            {
+                values.push((bytes[position] - b'0') * 10 + bytes[position + 1] - b'0');
+                position += 2;
+            }
+            continue;
+        }
+
+        let byte = bytes[position];
+        let required = match byte {
+            0..32 => CodeSet::A,
+            96.. => CodeSet::B,
+            _ => match code_set {
+                Some(CodeSet::A) => CodeSet::A,
+                _ => CodeSet::B,
+            },
+        };
+        push_code128_code_set(&mut values, &mut code_set, required);
+        values.push(match byte {
+            0..32 => byte + 64,
+            _ => byte - 32,
+        });
+        position += 1;
+    }
+    if values.is_empty() {
+        values.push(CODE128_START_B);
+    }
+
+    let checksum = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| i.max(1) * usize::from(*value))
+        .sum::<usize>()
+        % 103;
+
+    let mut symbol = LinearSymbol {
+        quiet_zone: 10,
+        ..Default::default()
+    };
+    for value in values
+        .iter()
+        .map(|value| usize::from(*value))
+        .chain([checksum, CODE128_STOP])
+    {
+        symbol.push_widths(CODE128_PATTERNS[value], true);
+    }
+    symbol.labels = vec![(symbol.modules.len() as f32 / 2.0, printable_label(input))];
+
+    Ok(EncodedBarcode {
+        symbol: Symbol::Linear(symbol),
+        check_digit: Some(checksum.to_string()),
+    })
+}
+
+/// Appends the start code or code set switch needed to continue in `target`.
+fn push_code128_code_set(values: &mut Vec<u8>, code_set: &mut Option<CodeSet>, target: CodeSet) {
+    if *code_set == Some(target) {
+        return;
+    }
+    values.push(match (code_set.is_none(), target) {
+        (true, CodeSet::A) => CODE128_START_A,
+        (true, CodeSet::B) => CODE128_START_B,
+        (true, CodeSet::C) => CODE128_START_C,
+        (false, CodeSet::A) => CODE128_SHIFT_A,
+        (false, CodeSet::B) => CODE128_SHIFT_B,
+        (false, CodeSet::C) => CODE128_SHIFT_C,
+    });
+    *code_set = Some(target);
+}
+
+fn printable_label(input: &str) -> String {
+    input
+        .chars()
+        .map(|character| match character.is_control() {
+            true => ' ',
+            false => character,
+        })
+        .collect()
+}
+
+// Code 39
+
+fn encode_code39(input: &str, with_check_digit: bool) -> Result<EncodedBarcode, String> {
+    let asterisk = CODE39_ALPHABET.len() - 1;
+    let mut values = input
+        .bytes()
+        .map(|byte| {
+            CODE39_ALPHABET[..asterisk]
+                .iter()
+                .position(|character| *character == byte)
+        })
+        .collect::<Option<Vec<usize>>>()
+        .ok_or("Code 39 only supports 0-9, A-Z, space and the symbols - . $ / + %.")?;
+    let check_digit = match with_check_digit {
+        true => {
+            let check_digit = values.iter().sum::<usize>() % 43;
+            values.push(check_digit);
+            Some(char::from(CODE39_ALPHABET[check_digit]).to_string())
+        }
+        false => None,
+    };
+
+    let mut symbol = LinearSymbol {
+        quiet_zone: 10,
+        ..Default::default()
+    };
+    for (i, value) in std::iter::once(asterisk)
+        .chain(values)
+        .chain([asterisk])
+        .enumerate()
+    {
+        if i > 0 {
+            symbol.modules.push(false);
+        }
+        let widths: Vec<u8> = (0..9)
+            .rev()
+            .map(|bit| match CODE39_PATTERNS[value] & (1 << bit) != 0 {
+                true => 3,
+                false => 1,
+            })
+            .collect();
+        symbol.push_widths(&widths, true);
+    }
+    symbol.labels = vec![(
+        symbol.modules.len() as f32 / 2.0,
+        format!("*{input}{}*", check_digit.clone().unwrap_or_default()),
+    )];
+
+    Ok(EncodedBarcode {
+        symbol: Symbol::Linear(symbol),
+        check_digit,
+    })
+}
+
+// Reed-Solomon
+
+/// GF(2^n) arithmetic for the Reed-Solomon codes of Data Matrix and Aztec.
+struct GaloisField {
+    exp: Vec<u32>,
+    log: Vec<u32>,
+}
+
+impl GaloisField {
+    fn new(primitive: u32, bits: u32) -> Self {
+        let size = 1usize << bits;
+        let mut exp = vec![0; size];
+        let mut log = vec![0; size];
+        let mut value = 1u32;
+        for exp in exp.iter_mut() {
+            *exp = value;
+            value <<= 1;
+            if value as usize >= size {
+                value ^= primitive;
+            }
+        }
+        for (power, value) in exp.iter().enumerate().take(size - 1) {
+            log[*value as usize] = power as u32;
+        }
+        Self { exp, log }
+    }
+
+    fn multiply(&self, a: u32, b: u32) -> u32 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        let order = self.exp.len() - 1;
+        self.exp[(self.log[a as usize] as usize + self.log[b as usize] as usize) % order]
+    }
+
+    /// Computes `ecc_len` check words for `data` with the generator roots α^1..α^ecc_len.
+    fn check_words(&self, data: &[u32], ecc_len: usize) -> Vec<u32> {
+        let mut generator = vec![1u32];
+        for i in 1..=ecc_len {
+            let root = self.exp[i % (self.exp.len() - 1)];
+            let mut next = vec![0u32; generator.len() + 1];
+            for (j, coefficient) in generator.iter().enumerate() {
+                next[j] ^= coefficient;
+                next[j + 1] ^= self.multiply(*coefficient, root);
+            }
+            generator = next;
+        }
+
+        let mut remainder = vec![0u32; ecc_len];
+        for word in data {
+            let factor = word ^ remainder[0];
+            remainder.rotate_left(1);
+            remainder[ecc_len - 1] = 0;
+            for (value, coefficient) in remainder.iter_mut().zip(&generator[1..]) {
+                *value ^= self.multiply(*coefficient, factor);
+            }
+        }
+        remainder
+    }
+}
+
+// Data Matrix (ECC 200)
+
+/// Square ECC 200 symbols as (symbol size, data region size, regions per side, data codewords,
+/// error correction codewords, interleaved blocks).
+const DATA_MATRIX_SYMBOLS: [(usize, usize, usize, usize, usize, usize); 24] = [
+    (10, 8, 1, 3, 5, 1),
+    (12, 10, 1, 5, 7, 1),
+    (14, 12, 1, 8, 10, 1),
+    (16, 14, 1, 12, 12, 1),
+    (18, 16, 1, 18, 14, 1),
+    (20, 18, 1, 22, 18, 1),
+    (22, 20, 1, 30, 20, 1),
+    (24, 22, 1, 36, 24, 1),
+    (26, 24, 1, 44, 28, 1),
+    (32, 14, 2, 62, 36, 1),
+    (36, 16, 2, 86, 42, 1),
+    (40, 18, 2, 114, 48, 1),
+    (44, 20, 2, 144, 56, 1),
+    (48, 22, 2, 174, 68, 1),
+    (52, 24, 2, 204, 84, 2),
+    (64, 14, 4, 280, 112, 2),
+    (72, 16, 4, 368, 144, 4),
+    (80, 18, 4, 456, 192, 4),
+    (88, 20, 4, 576, 224, 4),
+    (96, 22, 4, 696, 272, 4),
+    (104, 24, 4, 816, 336, 6),
+    (120, 18, 6, 1050, 408, 6),
+    (132, 20, 6, 1304, 496, 8),
+    (144, 22, 6, 1558, 620, 10),
+];
+
+const DATA_MATRIX_PAD: u8 = 129;
+const DATA_MATRIX_UPPER_SHIFT: u8 = 235;
+
+fn encode_data_matrix(input: &[u8]) -> Result<Vec<Vec<bool>>, String> {
+    // ASCII encodation: digit pairs are packed into one codeword and bytes above 127 use an
+    // upper shift.
+    let mut codewords: Vec<u8> = vec![];
+    let mut position = 0;
+    while position < input.len() {
+        let byte = input[position];
+        match input.get(position + 1) {
+            Some(next) if byte.is_ascii_digit() && next.is_ascii_digit() => {
+                codewords.push(130 + (byte - b'0') * 10 + (next - b'0'));
+                position += 2;
+                continue;
+            }
+            _ => {}
+        }
+        match byte {
+            0..128 => codewords.push(byte + 1),
+            _ => codewords.extend([DATA_MATRIX_UPPER_SHIFT, byte - 127]),
+        }
+        position += 1;
+    }
+
+    let &(size, region_size, regions, data_len, ecc_len, blocks) = DATA_MATRIX_SYMBOLS
+        .iter()
+        .find(|symbol| symbol.3 >= codewords.len())
+        .ok_or("Input is too long to fit in a Data Matrix symbol.")?;
+
+    if codewords.len() < data_len {
+        codewords.push(DATA_MATRIX_PAD);
+    }
+    while codewords.len() < data_len {
+        let position = codewords.len() + 1;
+        let pad = usize::from(DATA_MATRIX_PAD) + (149 * position) % 253 + 1;
+        codewords.push(match pad > 254 {
+            true => pad - 254,
+            false => pad,
+        } as u8);
+    }
+
+    let field = GaloisField::new(0x12D, 8);
+    let mut all: Vec<u8> = codewords.clone();
+    all.resize(data_len + ecc_len, 0);
+    for block in 0..blocks {
+        let data: Vec<u32> = codewords
+            .iter()
+            .skip(block)
+            .step_by(blocks)
+            .map(|codeword| u32::from(*codeword))
+            .collect();
+        let ecc = field.check_words(&data, ecc_len / blocks);
+        for (i, word) in ecc.iter().enumerate() {
+            all[data_len + block + i * blocks] = *word as u8;
+        }
+    }
+
+    let mapping_size = region_size * regions;
+    let placement = DataMatrixPlacement::place(&all, mapping_size, mapping_size);
+
+    let mut matrix = vec![vec![false; size]; size];
+    for (y, row) in placement.iter().enumerate() {
+        for (x, dark) in row.iter().enumerate() {
+            let symbol_y = y + 2 * (y / region_size) + 1;
+            let symbol_x = x + 2 * (x / region_size) + 1;
+            matrix[symbol_y][symbol_x] = *dark;
+        }
+    }
+    // Finder pattern around every data region: solid left and bottom edges, alternating top and
+    // right edges.
+    let block_size = region_size + 2;
+    for region in 0..regions {
+        let start = region * block_size;
+        let end = start + block_size - 1;
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[start] = true;
+            row[end] = i % 2 == 1;
+        }
+        matrix[end].fill(true);
+        for (i, module) in matrix[start].iter_mut().enumerate() {
+            *module = i % 2 == 0;
+        }
+    }
+    Ok(matrix)
+}
+
+/// The ECC 200 "utah" placement of codewords in the mapping matrix.
+struct DataMatrixPlacement<'a> {
+    codewords: &'a [u8],
+    rows: usize,
+    columns: usize,
+    bits: Vec<Vec<Option<bool>>>,
+}
+
+impl<'a> DataMatrixPlacement<'a> {
+    fn place(codewords: &'a [u8], rows: usize, columns: usize) -> Vec<Vec<bool>> {
+        let mut placement = Self {
+            codewords,
+            rows,
+            columns,
+            bits: vec![vec![None; columns]; rows],
+        };
+        let (rows, columns) = (rows as isize, columns as isize);
+        let mut index = 0;
+        let mut row = 4isize;
+        let mut column = 0isize;
+        loop {
+            if row == rows && column == 0 {
+                placement.corner(index, 1);
+                index += 1;
+            }
+            if row == rows - 2 && column == 0 && columns % 4 != 0 {
+                placement.corner(index, 2);
+                index += 1;
+            }
+            if row == rows - 2 && column == 0 && columns % 8 == 4 {
+                placement.corner(index, 3);
+                index += 1;
+            }
+            if row == rows + 4 && column == 2 && columns % 8 == 0 {
+                placement.corner(index, 4);
+                index += 1;
+            }
+            // Sweep upwards diagonally
+            loop {
+                if row < rows && column >= 0 && placement.is_free(row, column) {
+                    placement.utah(row, column, index);
+                    index += 1;
+                }
+                row -= 2;
+                column += 2;
+                if row < 0 || column >= columns {
+                    break;
+                }
+            }
+            row += 1;
+            column += 3;
+            // Sweep downwards diagonally
+            loop {
+                if row >= 0 && column < columns && placement.is_free(row, column) {
+                    placement.utah(row, column, index);
+                    index += 1;
+                }
+                row += 2;
+                column -= 2;
+                if row >= rows || column < 0 {
+                    break;
+                }
+            }
+            row += 3;
+            column += 1;
+            if row >= rows && column >= columns {
+                break;
+            }
+        }
+
+        let (last_row, last_column) = (placement.rows - 1, placement.columns - 1);
+        if placement.bits[last_row][last_column].is_none() {
+            placement.bits[last_row][last_column] = Some(true);
+            placement.bits[last_row][last_column - 1] = Some(false);
+            placement.bits[last_row - 1][last_column] = Some(false);
+            placement.bits[last_row - 1][last_column - 1] = Some(true);
+        }
+        placement
+            .bits
+            .into_iter()
+            .map(|row| row.into_iter().map(|bit| bit.unwrap_or(false)).collect())
+            .collect()
+    }
+
+    fn is_free(&self, row: isize, column: isize) -> bool {
+        self.bits[row as usize][column as usize].is_none()
+    }
+
+    fn module(&mut self, mut row: isize, mut column: isize, index: usize, bit: u8) {
+        let (rows, columns) = (self.rows as isize, self.columns as isize);
+        if row < 0 {
+            row += rows;
+            column += 4 - ((rows + 4) % 8);
+        }
+        if column < 0 {
+            column += columns;
+            row += 4 - ((columns + 4) % 8);
+        }
+        let value = self.codewords.get(index).copied().unwrap_or(0);
+        self.bits[row as usize][column as usize] = Some(value & (1 << (8 - bit)) != 0);
+    }
+
+    fn utah(&mut self, row: isize, column: isize, index: usize) {
+        self.module(row - 2, column - 2, index, 1);
+        self.module(row - 2, column - 1, index, 2);
+        self.module(row - 1, column - 2, index, 3);
+        self.module(row - 1, column - 1, index, 4);
+        self.module(row - 1, column, index, 5);
+        self.module(row, column - 2, index, 6);
+        self.module(row, column - 1, index, 7);
+        self.module(row, column, index, 8);
+    }
+
+    fn corner(&mut self, index: usize, case: u8) {
+        let (rows, columns) = (self.rows as isize, self.columns as isize);
+        let positions = match case {
+            1 => [
+                (rows - 1, 0),
+                (rows - 1, 1),
+                (rows - 1, 2),
+                (0, columns - 2),
+                (0, columns - 1),
+                (1, columns - 1),
+                (2, columns - 1),
+                (3, columns - 1),
+            ],
+            2 => [
+                (rows - 3, 0),
+                (rows - 2, 0),
+                (rows - 1, 0),
+                (0, columns - 4),
+                (0, columns - 3),
+                (0, columns - 2),
+                (0, columns - 1),
+                (1, columns - 1),
+            ],
+            3 => [
+                (rows - 3, 0),
+                (rows - 2, 0),
+                (rows - 1, 0),
+                (0, columns - 2),
+                (0, columns - 1),
+                (1, columns - 1),
+                (2, columns - 1),
+                (3, columns - 1),
+            ],
+            _ => [
+                (rows - 1, 0),
+                (rows - 1, columns - 1),
+                (0, columns - 3),
+                (0, columns - 2),
+                (0, columns - 1),
+                (1, columns - 3),
+                (1, columns - 2),
+                (1, columns - 1),
+            ],
+        };
+        for (bit, (row, column)) in positions.into_iter().enumerate() {
+            self.module(row, column, index, bit as u8 + 1);
+        }
+    }
+}
+
+// Aztec
+
+const AZTEC_MIN_ECC_PERCENT: usize = 33;
+const AZTEC_BINARY_SHIFT: u32 = 31;
+const AZTEC_MAX_BINARY_SHIFT: usize = 31 + 2047;
+
+fn aztec_word_size(layers: usize) -> usize {
+    match layers {
+        0..=2 => 6,
+        3..=8 => 8,
+        9..=22 => 10,
+        _ => 12,
+    }
+}
+
+fn aztec_field(word_size: usize) -> GaloisField {
+    match word_size {
+        4 => GaloisField::new(0x13, 4),
+        6 => GaloisField::new(0x43, 6),
+        8 => GaloisField::new(0x12D, 8),
+        10 => GaloisField::new(0x409, 10),
+        _ => GaloisField::new(0x1069, 12),
+    }
+}
+
+fn aztec_layer_bits(layers: usize, compact: bool) -> usize {
+    (if compact { 88 } else { 112 } + 16 * layers) * layers
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u32, count: usize) {
+    bits.extend((0..count).rev().map(|bit| value & (1 << bit) != 0));
+}
+
+/// Encodes the input with Aztec binary shifts from the initial upper mode, which is valid for
+/// any byte sequence.
+fn aztec_data_bits(input: &[u8]) -> Vec<bool> {
+    let mut bits = vec![];
+    for chunk in input.chunks(AZTEC_MAX_BINARY_SHIFT) {
+        push_bits(&mut bits, AZTEC_BINARY_SHIFT, 5);
+        match chunk.len() {
+            len @ 1..=31 => push_bits(&mut bits, len as u32, 5),
+            len => {
+                push_bits(&mut bits, 0, 5);
+                push_bits(&mut bits, (len - 31) as u32, 11);
+            }
+        }
+        for byte in chunk {
+            push_bits(&mut bits, u32::from(*byte), 8);
+        }
+    }
+    bits
+}
+
+/// Splits the bits into words, avoiding all-zero and all-one words by stuffing a
+/// complementary bit.
+fn aztec_stuff_bits(bits: &[bool], word_size: usize) -> Vec<u32> {
+    let mask = (1u32 << word_size) - 2;
+    let mut words = vec![];
+    let mut i = 0;
+    while i < bits.len() {
+        let mut word = 0u32;
+        for j in 0..word_size {
+            if bits.get(i + j).is_none_or(|bit| *bit) {
+                word |= 1 << (word_size - 1 - j);
+            }
+        }
+        if word & mask == mask {
+            words.push(word & mask);
+            i += word_size - 1;
+        } else if word & mask == 0 {
+            words.push(word | 1);
+            i += word_size - 1;
+        } else {
+            words.push(word);
+            i += word_size;
+        }
+    }
+    words
+}
+
+/// Appends check words and converts the message to bits, padded at the start to `total_bits`.
+fn aztec_message_bits(words: &[u32], total_bits: usize, word_size: usize) -> Vec<bool> {
+    let total_words = total_bits / word_size;
+    let ecc = aztec_field(word_size).check_words(words, total_words - words.len());
+    let mut bits = vec![false; total_bits % word_size];
+    for word in words.iter().chain(&ecc) {
+        push_bits(&mut bits, *word, word_size);
+    }
+    bits
+}
+
+fn encode_aztec(input: &[u8]) -> Result<Vec<Vec<bool>>, String> {
+    let bits = aztec_data_bits(input);
+    let ecc_bits = bits.len() * AZTEC_MIN_ECC_PERCENT / 100 + 11;
+    let total_size_bits = bits.len() + ecc_bits;
+
+    let (compact, layers, words, word_size, layer_bits) = (0..=32)
+        .find_map(|i| {
+            let compact = i <= 3;
+            let layers = if compact { i + 1 } else { i };
+            let layer_bits = aztec_layer_bits(layers, compact);
+            if total_size_bits > layer_bits {
+                return None;
+            }
+            let word_size = aztec_word_size(layers);
+            let words = aztec_stuff_bits(&bits, word_size);
+            let usable_bits = layer_bits - layer_bits % word_size;
+            if compact && words.len() > 64 {
+                return None;
+            }
+            (words.len() * word_size + ecc_bits <= usable_bits)
+                .then_some((compact, layers, words, word_size, layer_bits))
+        })
+        .ok_or("Input is too long to fit in an Aztec symbol.")?;
+
+    let message_bits = aztec_message_bits(&words, layer_bits, word_size);
+
+    let mut mode_message = vec![];
+    match compact {
+        true => {
+            push_bits(&mut mode_message, layers as u32 - 1, 2);
+            push_bits(&mut mode_message, words.len() as u32 - 1, 6);
+        }
+        false => {
+            push_bits(&mut mode_message, layers as u32 - 1, 5);
+            push_bits(&mut mode_message, words.len() as u32 - 1, 11);
+        }
+    }
+    let mode_words: Vec<u32> = mode_message
+        .chunks(4)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0, |word, bit| (word << 1) | u32::from(*bit))
+        })
+        .collect();
+    let mode_message = aztec_message_bits(&mode_words, if compact { 28 } else { 40 }, 4);
+
+    let base_size = if compact { 11 } else { 14 } + layers * 4;
+    let mut alignment_map: Vec<usize> = (0..base_size).collect();
+    let size = match compact {
+        true => base_size,
+        false => {
+            let size = base_size + 1 + 2 * ((base_size / 2 - 1) / 15);
+            let (original_center, center) = (base_size / 2, size / 2);
+            for i in 0..original_center {
+                let offset = i + i / 15;
+                alignment_map[original_center - i - 1] = center - offset - 1;
+                alignment_map[original_center + i] = center + offset + 1;
+            }
+            size
+        }
+    };
+    let mut matrix = vec![vec![false; size]; size];
+    let mut set = |x: usize, y: usize| matrix[y][x] = true;
+
+    // Data layers, spiralling from the outside in
+    let mut row_offset = 0;
+    for i in 0..layers {
+        let row_size = (layers - i) * 4 + if compact { 9 } else { 12 };
+        for j in 0..row_size {
+            let column_offset = j * 2;
+            for k in 0..2 {
+                let bit = |offset: usize| message_bits[row_offset + offset + column_offset + k];
+                let (near, far) = (i * 2 + k, base_size - 1 - i * 2 - k);
+                let (forward, backward) = (i * 2 + j, base_size - 1 - i * 2 - j);
+                if bit(0) {
+                    set(alignment_map[near], alignment_map[forward]);
+                }
+                if bit(row_size * 2) {
+                    set(alignment_map[forward], alignment_map[far]);
+                }
+                if bit(row_size * 4) {
+                    set(alignment_map[far], alignment_map[backward]);
+                }
+                if bit(row_size * 6) {
+                    set(alignment_map[backward], alignment_map[near]);
+                }
+            }
+        }
+        row_offset += row_size * 8;
+    }
+
+    // Mode message around the bullseye
+    let center = size / 2;
+    match compact {
+        true => {
+            for i in 0..7 {
+                let offset = center - 3 + i;
+                if mode_message[i] {
+                    set(offset, center - 5);
+                }
+                if mode_message[i + 7] {
+                    set(center + 5, offset);
+                }
+                if mode_message[20 - i] {
+                    set(offset, center + 5);
+                }
+                if mode_message[27 - i] {
+                    set(center - 5, offset);
+                }
+            }
+        }
+        false => {
+            for i in 0..10 {
+                let offset = center - 5 + i + i / 5;
+                if mode_message[i] {
+                    set(offset, center - 7);
+                }
+                if mode_message[i + 10] {
+                    set(center + 7, offset);
+                }
+                if mode_message[29 - i] {
+                    set(offset, center + 7);
+                }
+                if mode_message[39 - i] {
+                    set(center - 7, offset);
+                }
+            }
+        }
+    }
+
+    // Reference grid of full symbols
+    if !compact {
+        let mut offset = 0;
+        while offset / 16 * 15 < base_size / 2 - 1 {
+            for k in ((center & 1)..size).step_by(2) {
+                set(center - offset, k);
+                set(center + offset, k);
+                set(k, center - offset);
+                set(k, center + offset);
+            }
+            offset += 16;
+        }
+    }
+
+    // Bullseye with orientation marks
+    let bullseye = if compact { 5 } else { 7 };
+    for ring in (0..bullseye).step_by(2) {
+        for j in center - ring..=center + ring {
+            set(j, center - ring);
+            set(j, center + ring);
+            set(center - ring, j);
+            set(center + ring, j);
+        }
+    }
+    set(center - bullseye, center - bullseye);
+    set(center - bullseye + 1, center - bullseye);
+    set(center - bullseye, center - bullseye + 1);
+    set(center + bullseye, center - bullseye);
+    set(center + bullseye, center - bullseye + 1);
+    set(center + bullseye, center + bullseye - 1);
+
+    Ok(matrix)
+}
+
+// PDF417
+
+const PDF417_START: [u8; 8] = [8, 1, 1, 1, 1, 1, 1, 3];
+const PDF417_STOP: [u8; 9] = [7, 1, 1, 3, 1, 1, 1, 2, 1];
+/// Rows are drawn three modules high, the minimum row height of the specification.
+const PDF417_ROW_HEIGHT: usize = 3;
+const PDF417_MAX_CODEWORDS: usize = 928;
+const PDF417_PRIME: u32 = 929;
+const PDF417_TEXT_LATCH: u16 = 900;
+const PDF417_BYTE_LATCH: u16 = 901;
+const PDF417_NUMERIC_LATCH: u16 = 902;
+const PDF417_BYTE_SHIFT: u16 = 913;
+const PDF417_BYTE_LATCH_6: u16 = 924;
+const PDF417_PAD: u16 = 900;
+/// Digit runs shorter than this are cheaper in text compaction than in numeric compaction.
+const PDF417_MIN_NUMERIC_RUN: usize = 13;
+const PDF417_MIN_TEXT_RUN: usize = 5;
+
+/// Characters of the text compaction mixed submode by value; 25 to 29 are latches and shifts.
+const PDF417_MIXED: &[u8; 25] = b"0123456789&\r\t,:#-.$/+%*=^";
+/// Characters of the text compaction punctuation submode by value; 29 latches to alpha.
+const PDF417_PUNCTUATION: &[u8; 29] = b";<>@[\\]_`~!\r\t,:\n-.$/\"|*()?{}'";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Pdf417Mode {
+    Text,
+    Byte,
+    Numeric,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum TextSubmode {
+    Alpha,
+    Lower,
+    Mixed,
+    Punctuation,
+}
+
+fn encode_pdf417(input: &[u8]) -> Result<Vec<Vec<bool>>, String> {
+    let data = pdf417_data_codewords(input);
+    // The symbol length descriptor is itself a data codeword.
+    let data_len = data.len() + 1;
+    let level = match data_len {
+        0..=40 => 2,
+        41..=160 => 3,
+        161..=320 => 4,
+        _ => 5,
+    };
+    let ecc_len = 2 << level;
+    if data_len + ecc_len > PDF417_MAX_CODEWORDS {
+        return Err(format!(
+            "Input needs {data_len} data codewords, but a PDF417 symbol holds at most {}.",
+            PDF417_MAX_CODEWORDS - ecc_len
+        ));
+    }
+    let (rows, columns) = pdf417_dimensions(data_len + ecc_len)
+        .ok_or("Input is too long to fit in a PDF417 symbol.")?;
+
+    let padded_len = rows * columns - ecc_len;
+    let mut codewords = Vec::with_capacity(rows * columns);
+    codewords.push(padded_len as u16);
+    codewords.extend(&data);
+    codewords.resize(padded_len, PDF417_PAD);
+    let check_words = pdf417_check_words(&codewords, ecc_len);
+    codewords.extend(check_words);
+
+    let mut matrix = Vec::with_capacity(rows * PDF417_ROW_HEIGHT);
+    for (row, row_codewords) in codewords.chunks(columns).enumerate() {
+        let cluster = row % 3;
+        let row_group = 30 * (row / 3);
+        let rows_indicator = (rows - 1) / 3;
+        let level_indicator = level * 3 + (rows - 1) % 3;
+        let (left, right) = match cluster {
+            0 => (rows_indicator, columns - 1),
+            1 => (level_indicator, rows_indicator),
+            _ => (columns - 1, level_indicator),
+        };
+
+        let mut modules = LinearSymbol::default();
+        modules.push_widths(&PDF417_START, true);
+        let codewords = std::iter::once((row_group + left) as u16)
+            .chain(row_codewords.iter().cloned())
+            .chain(std::iter::once((row_group + right) as u16));
+        for codeword in codewords {
+            let pattern = PDF417_CODEWORD_PATTERNS[cluster][usize::from(codeword)];
+            modules
+                .modules
+                .extend((0..17).rev().map(|bit| pattern & (1 << bit) != 0));
+        }
+        modules.push_widths(&PDF417_STOP, true);
+        matrix.extend(std::iter::repeat_n(modules.modules, PDF417_ROW_HEIGHT));
+    }
+    Ok(matrix)
+}
+
+/// Picks the rows and data columns for `codeword_count` codewords whose symbol is closest to
+/// three times as wide as it is high.
+fn pdf417_dimensions(codeword_count: usize) -> Option<(usize, usize)> {
+    (1..=30)
+        .filter_map(|columns: usize| {
+            let rows = codeword_count.div_ceil(columns).max(3);
+            (rows <= 90 && rows * columns <= PDF417_MAX_CODEWORDS).then_some((rows, columns))
+        })
+        .min_by_key(|(rows, columns)| {
+            let width = 17 * columns + 69;
+            let height = rows * PDF417_ROW_HEIGHT;
+            (width.abs_diff(3 * height), rows * columns)
+        })
+}
+
+/// Splits the input into text, byte and numeric compaction segments, starting in text
+/// compaction as every symbol does.
+fn pdf417_data_codewords(input: &[u8]) -> Vec<u16> {
+    let mut codewords = vec![];
+    let mut mode = Pdf417Mode::Text;
+    let mut submode = TextSubmode::Alpha;
+    let mut position = 0;
+    while position < input.len() {
+        let rest = &input[position..];
+        let digits = rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if digits >= PDF417_MIN_NUMERIC_RUN {
+            codewords.push(PDF417_NUMERIC_LATCH);
+            mode = Pdf417Mode::Numeric;
+            pdf417_numeric(&rest[..digits], &mut codewords);
+            position += digits;
+            continue;
+        }
+        let text = pdf417_text_run(rest);
+        if text >= PDF417_MIN_TEXT_RUN || text == rest.len() {
+            if mode != Pdf417Mode::Text {
+                codewords.push(PDF417_TEXT_LATCH);
+                mode = Pdf417Mode::Text;
+                submode = TextSubmode::Alpha;
+            }
+            pdf417_text(&rest[..text], &mut submode, &mut codewords);
+            position += text;
+            continue;
+        }
+        let bytes = pdf417_byte_run(rest);
+        if bytes == 1 && mode == Pdf417Mode::Text {
+            codewords.push(PDF417_BYTE_SHIFT);
+            codewords.push(u16::from(rest[0]));
+        } else {
+            codewords.push(match bytes % 6 {
+                0 => PDF417_BYTE_LATCH_6,
+                _ => PDF417_BYTE_LATCH,
+            });
+            mode = Pdf417Mode::Byte;
+            pdf417_bytes(&rest[..bytes], &mut codewords);
+        }
+        position += bytes;
+    }
+    codewords
+}
+
+fn is_pdf417_text(byte: u8) -> bool {
+    byte == b' '
+        || byte.is_ascii_alphanumeric()
+        || PDF417_MIXED.contains(&byte)
+        || PDF417_PUNCTUATION.contains(&byte)
+}
+
+/// Counts the leading bytes that text compaction can encode, up to a long run of digits.
+fn pdf417_text_run(input: &[u8]) -> usize {
+    let mut count = 0;
+    while count < input.len() && is_pdf417_text(input[count]) {
+        let digits = input[count..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if digits >= PDF417_MIN_NUMERIC_RUN {
+            break;
+        }
+        count += digits.max(1);
+    }
+    count.min(input.len())
+}
+
+/// Counts the leading bytes up to where a numeric or text compaction segment pays off.
+fn pdf417_byte_run(input: &[u8]) -> usize {
+    (1..input.len())
+        .find(|position| {
+            let rest = &input[*position..];
+            rest.iter().take_while(|byte| byte.is_ascii_digit()).count() >= PDF417_MIN_NUMERIC_RUN
+                || pdf417_text_run(rest) >= PDF417_MIN_TEXT_RUN
+        })
+        .unwrap_or(input.len())
+}
+
+/// Encodes two text characters per codeword as values 0 to 29 of the current submode.
+fn pdf417_text(text: &[u8], submode: &mut TextSubmode, codewords: &mut Vec<u16>) {
+    let mut values: Vec<u16> = vec![];
+    let mut position = 0;
+    while position < text.len() {
+        let byte = text[position];
+        let mixed = PDF417_MIXED.iter().position(|character| *character == byte);
+        let punctuation = PDF417_PUNCTUATION
+            .iter()
+            .position(|character| *character == byte);
+        let value = match (*submode, byte) {
+            (TextSubmode::Alpha, b'A'..=b'Z') | (TextSubmode::Lower, b'a'..=b'z') => {
+                Some(u16::from(byte.to_ascii_uppercase() - b'A'))
+            }
+            (TextSubmode::Alpha | TextSubmode::Lower | TextSubmode::Mixed, b' ') => Some(26),
+            (TextSubmode::Mixed, _) if mixed.is_some() => mixed.map(|value| value as u16),
+            (TextSubmode::Punctuation, _) if punctuation.is_some() => {
+                punctuation.map(|value| value as u16)
+            }
+            _ => None,
+        };
+        if let Some(value) = value {
+            values.push(value);
+            position += 1;
+            continue;
+        }
+
+        match (*submode, byte) {
+            (TextSubmode::Alpha | TextSubmode::Mixed, b'a'..=b'z') => {
+                values.push(27);
+                *submode = TextSubmode::Lower;
+            }
+            (TextSubmode::Lower, b'A'..=b'Z') => {
+                // Shift to alpha for a single character.
+                values.extend([27, u16::from(byte - b'A')]);
+                position += 1;
+            }
+            (TextSubmode::Mixed, b'A'..=b'Z') => {
+                values.push(28);
+                *submode = TextSubmode::Alpha;
+            }
+            (TextSubmode::Alpha | TextSubmode::Lower, _) if mixed.is_some() => {
+                values.push(28);
+                *submode = TextSubmode::Mixed;
+            }
+            (TextSubmode::Punctuation, _) => {
+                values.push(29);
+                *submode = TextSubmode::Alpha;
+            }
+            (TextSubmode::Mixed, _)
+                if text
+                    .get(position + 1)
+                    .is_some_and(|next| PDF417_PUNCTUATION.contains(next)) =>
+            {
+                values.push(25);
+                *submode = TextSubmode::Punctuation;
+            }
+            _ => {
+                // Shift to punctuation for a single character.
+                values.extend([29, punctuation.unwrap_or_default() as u16]);
+                position += 1;
+            }
+        }
+    }
+    if values.len() % 2 == 1 {
+        // 29 shifts to punctuation in the other submodes but latches back to alpha here.
+        values.push(29);
+        if *submode == TextSubmode::Punctuation {
+            *submode = TextSubmode::Alpha;
+        }
+    }
+    codewords.extend(values.chunks(2).map(|pair| pair[0] * 30 + pair[1]));
+}
+
+/// Encodes groups of six bytes as five base 900 codewords and the remaining bytes as one
+/// codeword each.
+fn pdf417_bytes(bytes: &[u8], codewords: &mut Vec<u16>) {
+    for chunk in bytes.chunks(6) {
+        if chunk.len() < 6 {
+            codewords.extend(chunk.iter().map(|byte| u16::from(*byte)));
+            continue;
+        }
+        let mut value = chunk
+            .iter()
+            .fold(0u64, |value, byte| value << 8 | u64::from(*byte));
+        let mut group = [0u16; 5];
+        for codeword in group.iter_mut().rev() {
+            *codeword = (value % 900) as u16;
+            value /= 900;
+        }
+        codewords.extend(group);
+    }
+}
+
+/// Encodes groups of up to 44 digits, prefixed with a 1 to keep leading zeros, in base 900.
+fn pdf417_numeric(digits: &[u8], codewords: &mut Vec<u16>) {
+    let base = BigUint::from(900u32);
+    for chunk in digits.chunks(44) {
+        let mut value = BigUint::parse_bytes(&[b"1", chunk].concat(), 10).unwrap_or_default();
+        let mut group = vec![];
+        while !value.is_zero() {
+            group.push((&value % &base).to_u16().unwrap_or_default());
+            value /= &base;
+        }
+        codewords.extend(group.iter().rev());
+    }
+}
+
+/// Computes `ecc_len` Reed-Solomon check codewords over GF(929) with the generator roots
+/// 3^1..3^ecc_len.
+fn pdf417_check_words(data: &[u16], ecc_len: usize) -> Vec<u16> {
+    // Coefficients of the generator polynomial, lowest degree first.
+    let mut generator = vec![1u32];
+    let mut root = 1u32;
+    for _ in 0..ecc_len {
+        root = root * 3 % PDF417_PRIME;
+        let mut next = vec![0u32; generator.len() + 1];
+        for (i, coefficient) in generator.iter().enumerate() {
+            next[i + 1] = (next[i + 1] + coefficient) % PDF417_PRIME;
+            next[i] = (next[i] + PDF417_PRIME - coefficient * root % PDF417_PRIME) % PDF417_PRIME;
+        }
+        generator = next;
+    }
+
+    let mut remainder = vec![0u32; ecc_len];
+    for codeword in data {
+        let factor = (u32::from(*codeword) + remainder[ecc_len - 1]) % PDF417_PRIME;
+        for i in (1..ecc_len).rev() {
+            remainder[i] = (remainder[i - 1] + PDF417_PRIME - factor * generator[i] % PDF417_PRIME)
+                % PDF417_PRIME;
+        }
+        remainder[0] = (PDF417_PRIME - factor * generator[0] % PDF417_PRIME) % PDF417_PRIME;
+    }
+    remainder
+        .iter()
+        .rev()
+        .map(|value| ((PDF417_PRIME - value) % PDF417_PRIME) as u16)
+        .collect()
+}
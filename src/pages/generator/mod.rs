@@ -3,6 +3,7 @@ use dioxus_free_icons::icons::fa_solid_icons::FaScrewdriverWrench;
 use dioxus_free_icons::Icon;
 use strum_macros::EnumIter;
 
+pub mod barcode_generator;
 pub mod hash_generator;
 pub mod lorem_ipsum;
 pub mod password_generator;
@@ -20,6 +21,7 @@ pub static CATEGORY_ENTRY: CategoryEntry = CategoryEntry {
         }
     },
 };
+use barcode_generator::BarcodeGenerator;
 use hash_generator::HashGenerator;
 use lorem_ipsum::LoremIpsum;
 use password_generator::PasswordGenerator;
@@ -38,6 +40,8 @@ pub enum GeneratorRoute {
     LoremIpsum {},
     #[route("/qr-code")]
     QrCodeGenerator {},
+    #[route("/barcode")]
+    BarcodeGenerator {},
     #[route("/uuid")]
     UuidGenerator {},
 }
@@ -68,6 +72,7 @@ impl WidgetRoute for GeneratorRoute {
             Self::PasswordGenerator { .. } => Some(&password_generator::WIDGET_ENTRY),
             Self::LoremIpsum { .. } => Some(&lorem_ipsum::WIDGET_ENTRY),
             Self::QrCodeGenerator { .. } => Some(&qr_code_generator::WIDGET_ENTRY),
+            Self::BarcodeGenerator { .. } => Some(&barcode_generator::WIDGET_ENTRY),
             Self::UuidGenerator { .. } => Some(&uuid_generator::WIDGET_ENTRY),
             _ => None,
         }
@@ -69,7 +69,7 @@ pub fn QrCodeGenerator() -> Element {
 #[derive(
     Copy, Clone, Default, Debug, Display, EnumIter, EnumString, Hash, IntoStaticStr, PartialEq,
 )]
-enum Ecc {
+pub(crate) enum Ecc {
     #[default]
     Low,
     Medium,
@@ -1,3 +1,5 @@
+pub mod barcode;
+
 /// Adds a delimiter to a number string at a given frequency.
 pub fn add_number_delimiters(number_str: String, delimiter: char, frequency: usize) -> String {
     number_str
@@ -26,3 +28,251 @@ pub fn sanitize_string(string: String) -> String {
         .filter(|character| character.is_ascii_alphanumeric())
         .collect::<String>()
 }
+
+/// Formats bytes like `hexdump -C`: offsets, 16 hex bytes per row and an ASCII gutter.
+pub fn hex_dump(bytes: &[u8]) -> String {
+    hex_dump_with(bytes, 16, 8, false)
+}
+
+/// Formats bytes like `hexdump -C` with a custom row width, adding an extra space after every
+/// `group_size` bytes.
+pub fn hex_dump_with(
+    bytes: &[u8],
+    bytes_per_row: usize,
+    group_size: usize,
+    uppercase: bool,
+) -> String {
+    let bytes_per_row = bytes_per_row.max(1);
+    bytes
+        .chunks(bytes_per_row)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex = (0..bytes_per_row)
+                .map(|i| match chunk.get(i) {
+                    Some(byte) if uppercase => format!("{byte:02X} "),
+                    Some(byte) => format!("{byte:02x} "),
+                    None => "   ".to_string(),
+                })
+                .enumerate()
+                .fold(String::new(), |mut hex, (i, byte)| {
+                    if i > 0 && group_size > 0 && i.is_multiple_of(group_size) {
+                        hex.push(' ');
+                    }
+                    hex.push_str(&byte);
+                    hex
+                });
+            format!(
+                "{:08x}  {hex} |{}|",
+                row * bytes_per_row,
+                ascii_gutter(chunk)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Printable ASCII bytes as themselves and everything else as `.`, as in hex dump gutters.
+pub fn ascii_gutter(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| match byte.is_ascii_graphic() || *byte == b' ' {
+            true => char::from(*byte),
+            false => '.',
+        })
+        .collect()
+}
+
+/// A file format recognized by its leading magic bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FileSignature {
+    pub name: &'static str,
+    pub mime_type: &'static str,
+    pub extension: &'static str,
+}
+
+const FILE_SIGNATURES: [(&[u8], FileSignature); 14] = [
+    (
+        b"\x89PNG\r\n\x1a\n",
+        FileSignature {
+            name: "PNG image",
+            mime_type: "image/png",
+            extension: "png",
+        },
+    ),
+    (
+        b"\xff\xd8\xff",
+        FileSignature {
+            name: "JPEG image",
+            mime_type: "image/jpeg",
+            extension: "jpg",
+        },
+    ),
+    (
+        b"GIF8",
+        FileSignature {
+            name: "GIF image",
+            mime_type: "image/gif",
+            extension: "gif",
+        },
+    ),
+    (
+        b"BM",
+        FileSignature {
+            name: "BMP image",
+            mime_type: "image/bmp",
+            extension: "bmp",
+        },
+    ),
+    (
+        b"\x00\x00\x01\x00",
+        FileSignature {
+            name: "ICO image",
+            mime_type: "image/x-icon",
+            extension: "ico",
+        },
+    ),
+    (
+        b"%PDF-",
+        FileSignature {
+            name: "PDF document",
+            mime_type: "application/pdf",
+            extension: "pdf",
+        },
+    ),
+    (
+        b"\x1f\x8b",
+        FileSignature {
+            name: "gzip archive",
+            mime_type: "application/gzip",
+            extension: "gz",
+        },
+    ),
+    (
+        b"PK\x03\x04",
+        FileSignature {
+            name: "ZIP archive",
+            mime_type: "application/zip",
+            extension: "zip",
+        },
+    ),
+    (
+        b"PK\x05\x06",
+        FileSignature {
+            name: "ZIP archive (empty)",
+            mime_type: "application/zip",
+            extension: "zip",
+        },
+    ),
+    (
+        b"\x28\xb5\x2f\xfd",
+        FileSignature {
+            name: "Zstandard archive",
+            mime_type: "application/zstd",
+            extension: "zst",
+        },
+    ),
+    (
+        b"BZh",
+        FileSignature {
+            name: "bzip2 archive",
+            mime_type: "application/x-bzip2",
+            extension: "bz2",
+        },
+    ),
+    (
+        b"7z\xbc\xaf\x27\x1c",
+        FileSignature {
+            name: "7-Zip archive",
+            mime_type: "application/x-7z-compressed",
+            extension: "7z",
+        },
+    ),
+    (
+        b"\x7fELF",
+        FileSignature {
+            name: "ELF executable",
+            mime_type: "application/x-elf",
+            extension: "elf",
+        },
+    ),
+    (
+        b"\x00asm",
+        FileSignature {
+            name: "WebAssembly module",
+            mime_type: "application/wasm",
+            extension: "wasm",
+        },
+    ),
+];
+
+const WEBP_SIGNATURE: FileSignature = FileSignature {
+    name: "WebP image",
+    mime_type: "image/webp",
+    extension: "webp",
+};
+
+const PROTOBUF_SIGNATURE: FileSignature = FileSignature {
+    name: "Protocol Buffers message (probable)",
+    mime_type: "application/x-protobuf",
+    extension: "bin",
+};
+
+/// Detects the file format of the bytes from their magic bytes, falling back to checking
+/// whether binary (non UTF-8) bytes parse as a protobuf wire format message.
+pub fn detect_file_signature(bytes: &[u8]) -> Option<FileSignature> {
+    if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        return Some(WEBP_SIGNATURE);
+    }
+    FILE_SIGNATURES
+        .iter()
+        .find(|(magic, _)| bytes.starts_with(magic))
+        .map(|(_, signature)| *signature)
+        .or_else(|| {
+            (std::str::from_utf8(bytes).is_err() && is_protobuf_message(bytes))
+                .then_some(PROTOBUF_SIGNATURE)
+        })
+}
+
+/// Checks whether the bytes are a sequence of well-formed protobuf fields.
+fn is_protobuf_message(bytes: &[u8]) -> bool {
+    fn read_varint(bytes: &[u8], position: &mut usize) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *bytes.get(*position)?;
+            *position += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    let mut position = 0;
+    while position < bytes.len() {
+        let Some(key) = read_varint(bytes, &mut position) else {
+            return false;
+        };
+        if key >> 3 == 0 {
+            return false;
+        }
+        let length = match key & 0x7 {
+            0 => match read_varint(bytes, &mut position) {
+                Some(_) => 0,
+                None => return false,
+            },
+            1 => 8,
+            2 => match read_varint(bytes, &mut position) {
+                Some(length) => length as usize,
+                None => return false,
+            },
+            5 => 4,
+            _ => return false,
+        };
+        position = match position.checked_add(length) {
+            Some(end) if end <= bytes.len() => end,
+            _ => return false,
+        };
+    }
+    !bytes.is_empty()
+}